@@ -0,0 +1,295 @@
+use crate::{Matrix, MatrixError};
+use rayon::prelude::*;
+use std::io::{BufRead, BufReader, BufWriter, Read, Write};
+
+/// How to treat empty fields and fields listed in `CsvOptions::na_values`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CsvMissingValue {
+    /// Returns `MatrixError::ParseError`.
+    Error,
+    /// Replaces the field with the value, for example `f64::NAN`.
+    Fill(f64),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct CsvOptions {
+    pub delimiter: char,
+    /// Whether the first record is a header.
+    pub has_header: bool,
+    /// If `true`, each record is a column of the matrix instead of a row.
+    pub column_major: bool,
+    pub missing_value: CsvMissingValue,
+    /// Fields treated as missing in addition to empty fields, such as `NA`.
+    pub na_values: Vec<String>,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            has_header: false,
+            column_major: false,
+            missing_value: CsvMissingValue::Error,
+            na_values: vec![],
+        }
+    }
+}
+
+impl CsvOptions {
+    /// Tab separated values.
+    pub fn tsv() -> Self {
+        Self {
+            delimiter: '\t',
+            ..Default::default()
+        }
+    }
+}
+
+fn parse_error(line: usize, message: impl Into<String>) -> MatrixError {
+    MatrixError::ParseError {
+        line,
+        message: message.into(),
+    }
+}
+
+/// Splits a record into fields. Fields may be quoted with `"` and `""` is an escaped quote.
+fn split_record(text: &str, delimiter: char) -> Result<Vec<String>, String> {
+    let mut fields = vec![];
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if quoted {
+            if c == '"' {
+                if chars.peek() == Some(&'"') {
+                    field.push('"');
+                    chars.next();
+                } else {
+                    quoted = false;
+                }
+            } else {
+                field.push(c);
+            }
+        } else if c == '"' {
+            quoted = true;
+        } else if c == delimiter {
+            fields.push(std::mem::take(&mut field));
+        } else {
+            field.push(c);
+        }
+    }
+
+    if quoted {
+        return Err("quoted field is not closed".to_owned());
+    }
+    fields.push(field);
+
+    Ok(fields)
+}
+
+fn quote_field(field: &str, delimiter: char) -> String {
+    if field.contains(delimiter) || field.contains('"') {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+impl Matrix {
+    /// # Read CSV
+    /// The header record is skipped if `options.has_header`.
+    pub fn read_csv<R: Read>(reader: R, options: &CsvOptions) -> Result<Self, MatrixError> {
+        Ok(Self::read_csv_with_header(reader, options)?.1)
+    }
+
+    /// # Read CSV
+    /// Returns `(header, matrix)`. `header` is empty unless `options.has_header`.
+    pub fn read_csv_with_header<R: Read>(
+        reader: R,
+        options: &CsvOptions,
+    ) -> Result<(Vec<String>, Self), MatrixError> {
+        let mut header = vec![];
+        let mut records = vec![];
+        let mut len = None;
+
+        for (k, text) in BufReader::new(reader).lines().enumerate() {
+            let line = k + 1;
+            let text = text?;
+            let text = text.trim_end_matches('\r');
+            if text.trim().is_empty() {
+                continue;
+            }
+
+            let fields = split_record(text, options.delimiter).map_err(|m| parse_error(line, m))?;
+
+            if options.has_header && header.is_empty() && records.is_empty() {
+                header = fields.into_iter().map(|f| f.trim().to_owned()).collect();
+                len = Some(header.len());
+                continue;
+            }
+
+            match len {
+                Some(len) if len != fields.len() => {
+                    return Err(parse_error(
+                        line,
+                        format!("{} fields are expected but found {}", len, fields.len()),
+                    ))
+                }
+                _ => len = Some(fields.len()),
+            }
+
+            let record = fields
+                .iter()
+                .map(|f| {
+                    let f = f.trim();
+                    if f.is_empty() || options.na_values.iter().any(|na| na == f) {
+                        return match options.missing_value {
+                            CsvMissingValue::Error => Err(parse_error(line, "missing value")),
+                            CsvMissingValue::Fill(value) => Ok(value),
+                        };
+                    }
+
+                    f.parse::<f64>()
+                        .map_err(|_| parse_error(line, format!("`{}` is not a number", f)))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            records.push(record);
+        }
+
+        let len = match (records.len(), len) {
+            (0, _) | (_, None) => return Ok((header, Matrix::new(0, 0))),
+            (_, Some(len)) => len,
+        };
+
+        let mat = if options.column_major {
+            Matrix::from(len, records.concat())?
+        } else {
+            let rows = records.len();
+            let elems = (0..rows * len)
+                .into_par_iter()
+                .map(|k| records[k % rows][k / rows])
+                .collect();
+            Matrix::from(rows, elems)?
+        };
+
+        Ok((header, mat))
+    }
+
+    /// # Write CSV
+    /// `header` is written as the first record if it is given.
+    pub fn write_csv<W: Write>(
+        &self,
+        writer: W,
+        options: &CsvOptions,
+        header: Option<&[&str]>,
+    ) -> Result<(), MatrixError> {
+        let (records, len) = if options.column_major {
+            (self.cols(), self.rows())
+        } else {
+            (self.rows(), self.cols())
+        };
+        let delimiter = options.delimiter.to_string();
+
+        let mut writer = BufWriter::new(writer);
+
+        if let Some(header) = header {
+            if header.len() != len {
+                return Err(MatrixError::DimensionMismatch);
+            }
+            let header = header
+                .iter()
+                .map(|h| quote_field(h, options.delimiter))
+                .collect::<Vec<_>>();
+            writeln!(writer, "{}", header.join(&delimiter))?;
+        }
+
+        for r in 0..records {
+            let record = (0..len)
+                .map(|l| {
+                    if options.column_major {
+                        self[(l, r)]
+                    } else {
+                        self[(r, l)]
+                    }
+                })
+                .map(|e| e.to_string())
+                .collect::<Vec<_>>();
+            writeln!(writer, "{}", record.join(&delimiter))?;
+        }
+        writer.flush()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn it_works() {
+        let text = "x,y,\"label, with comma\"\n1.0,2.0,3.0\n4,5,6\n";
+        let options = CsvOptions {
+            has_header: true,
+            ..Default::default()
+        };
+        let (header, a) = Matrix::read_csv_with_header(text.as_bytes(), &options).unwrap();
+
+        assert_eq!(header, vec!["x", "y", "label, with comma"]);
+        assert_eq!(
+            a,
+            mat!(
+                1.0, 2.0, 3.0;
+                4.0, 5.0, 6.0
+            )
+        );
+
+        let mut buf = vec![];
+        let header = header.iter().map(|h| h.as_str()).collect::<Vec<_>>();
+        a.write_csv(&mut buf, &options, Some(&header)).unwrap();
+
+        assert_eq!(String::from_utf8(buf).unwrap(), text.replace(".0", ""));
+    }
+
+    #[test]
+    fn tsv() {
+        let text = "1\t2\n\t4\nNA\t6\n";
+        let options = CsvOptions {
+            column_major: true,
+            missing_value: CsvMissingValue::Fill(0.0),
+            na_values: vec!["NA".to_owned()],
+            ..CsvOptions::tsv()
+        };
+        let a = Matrix::read_csv(text.as_bytes(), &options).unwrap();
+
+        assert_eq!(
+            a,
+            mat!(
+                1.0, 0.0, 0.0;
+                2.0, 4.0, 6.0
+            )
+        );
+
+        let mut buf = vec![];
+        a.write_csv(&mut buf, &options, None).unwrap();
+        let b = Matrix::read_csv(buf.as_slice(), &options).unwrap();
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn parse_error() {
+        let text = "1,2\n3,x\n";
+        match Matrix::read_csv(text.as_bytes(), &CsvOptions::default()) {
+            Err(MatrixError::ParseError { line, .. }) => assert_eq!(line, 2),
+            _ => panic!(),
+        }
+
+        let text = "1,2\n\n3\n";
+        match Matrix::read_csv(text.as_bytes(), &CsvOptions::default()) {
+            Err(MatrixError::ParseError { line, .. }) => assert_eq!(line, 3),
+            _ => panic!(),
+        }
+    }
+}
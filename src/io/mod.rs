@@ -0,0 +1,22 @@
+use crate::MatrixError;
+use zip::result::ZipError;
+
+pub mod csv;
+pub mod mtx;
+pub mod npy;
+
+pub use csv::*;
+pub use mtx::*;
+pub use npy::*;
+
+impl From<std::io::Error> for MatrixError {
+    fn from(e: std::io::Error) -> Self {
+        MatrixError::Others(Box::new(e))
+    }
+}
+
+impl From<ZipError> for MatrixError {
+    fn from(e: ZipError) -> Self {
+        MatrixError::Others(Box::new(e))
+    }
+}
@@ -0,0 +1,691 @@
+use crate::{number::c64, Matrix, MatrixError, Number, SparseMatrix, SymmetricPackedMatrix};
+use std::{
+    fmt,
+    io::{BufRead, BufReader, BufWriter, Read, Write},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MatrixMarketFormat {
+    Coordinate,
+    Array,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MatrixMarketField {
+    Real,
+    Complex,
+    Integer,
+    Pattern,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MatrixMarketSymmetry {
+    General,
+    Symmetric,
+    SkewSymmetric,
+    Hermitian,
+}
+
+impl MatrixMarketSymmetry {
+    /// Whether the element `(i, j)` is written in a file with this symmetry.
+    fn stores(&self, i: usize, j: usize) -> bool {
+        match self {
+            MatrixMarketSymmetry::General => true,
+            MatrixMarketSymmetry::Symmetric | MatrixMarketSymmetry::Hermitian => j <= i,
+            MatrixMarketSymmetry::SkewSymmetric => j < i,
+        }
+    }
+
+    fn first_row(&self, j: usize) -> usize {
+        match self {
+            MatrixMarketSymmetry::General => 0,
+            MatrixMarketSymmetry::Symmetric | MatrixMarketSymmetry::Hermitian => j,
+            MatrixMarketSymmetry::SkewSymmetric => j + 1,
+        }
+    }
+}
+
+/// `%%MatrixMarket matrix <format> <field> <symmetry>`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MatrixMarketHeader {
+    pub format: MatrixMarketFormat,
+    pub field: MatrixMarketField,
+    pub symmetry: MatrixMarketSymmetry,
+}
+
+impl MatrixMarketHeader {
+    fn parse(line: &str) -> Result<Self, String> {
+        let tokens = line
+            .split_whitespace()
+            .map(|t| t.to_lowercase())
+            .collect::<Vec<_>>();
+
+        if tokens.len() != 5 || tokens[0] != "%%matrixmarket" || tokens[1] != "matrix" {
+            return Err("`%%MatrixMarket matrix` banner is expected".to_owned());
+        }
+
+        let format = match tokens[2].as_str() {
+            "coordinate" => MatrixMarketFormat::Coordinate,
+            "array" => MatrixMarketFormat::Array,
+            other => return Err(format!("unknown format `{}`", other)),
+        };
+        let field = match tokens[3].as_str() {
+            "real" => MatrixMarketField::Real,
+            "complex" => MatrixMarketField::Complex,
+            "integer" => MatrixMarketField::Integer,
+            "pattern" => MatrixMarketField::Pattern,
+            other => return Err(format!("unknown field `{}`", other)),
+        };
+        let symmetry = match tokens[4].as_str() {
+            "general" => MatrixMarketSymmetry::General,
+            "symmetric" => MatrixMarketSymmetry::Symmetric,
+            "skew-symmetric" => MatrixMarketSymmetry::SkewSymmetric,
+            "hermitian" => MatrixMarketSymmetry::Hermitian,
+            other => return Err(format!("unknown symmetry `{}`", other)),
+        };
+
+        if format == MatrixMarketFormat::Array && field == MatrixMarketField::Pattern {
+            return Err("pattern field is not allowed in array format".to_owned());
+        }
+
+        Ok(Self {
+            format,
+            field,
+            symmetry,
+        })
+    }
+}
+
+impl fmt::Display for MatrixMarketHeader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let format = match self.format {
+            MatrixMarketFormat::Coordinate => "coordinate",
+            MatrixMarketFormat::Array => "array",
+        };
+        let field = match self.field {
+            MatrixMarketField::Real => "real",
+            MatrixMarketField::Complex => "complex",
+            MatrixMarketField::Integer => "integer",
+            MatrixMarketField::Pattern => "pattern",
+        };
+        let symmetry = match self.symmetry {
+            MatrixMarketSymmetry::General => "general",
+            MatrixMarketSymmetry::Symmetric => "symmetric",
+            MatrixMarketSymmetry::SkewSymmetric => "skew-symmetric",
+            MatrixMarketSymmetry::Hermitian => "hermitian",
+        };
+
+        write!(f, "%%MatrixMarket matrix {} {} {}", format, field, symmetry)
+    }
+}
+
+/// Element types which can be read from and written to Matrix Market files.
+pub trait MatrixMarketElement: Number {
+    /// Field used when writing.
+    const FIELD: MatrixMarketField;
+
+    fn parse_mtx(field: MatrixMarketField, tokens: &[&str]) -> Result<Self, String>;
+    fn format_mtx(&self) -> String;
+    fn conj_mtx(self) -> Self;
+}
+
+fn parse_value(field: MatrixMarketField, tokens: &[&str]) -> Result<(f64, f64), String> {
+    let expected = match field {
+        MatrixMarketField::Pattern => 0,
+        MatrixMarketField::Real | MatrixMarketField::Integer => 1,
+        MatrixMarketField::Complex => 2,
+    };
+    if tokens.len() != expected {
+        return Err(format!(
+            "{} value token(s) are expected but found {}",
+            expected,
+            tokens.len()
+        ));
+    }
+
+    let parse = |token: &str| {
+        token
+            .parse::<f64>()
+            .map_err(|_| format!("`{}` is not a number", token))
+    };
+
+    match field {
+        MatrixMarketField::Pattern => Ok((1.0, 0.0)),
+        MatrixMarketField::Real | MatrixMarketField::Integer => Ok((parse(tokens[0])?, 0.0)),
+        MatrixMarketField::Complex => Ok((parse(tokens[0])?, parse(tokens[1])?)),
+    }
+}
+
+impl MatrixMarketElement for f64 {
+    const FIELD: MatrixMarketField = MatrixMarketField::Real;
+
+    fn parse_mtx(field: MatrixMarketField, tokens: &[&str]) -> Result<Self, String> {
+        if field == MatrixMarketField::Complex {
+            return Err("complex entries can not be read as f64".to_owned());
+        }
+
+        Ok(parse_value(field, tokens)?.0)
+    }
+
+    fn format_mtx(&self) -> String {
+        format!("{}", self)
+    }
+
+    fn conj_mtx(self) -> Self {
+        self
+    }
+}
+
+impl MatrixMarketElement for c64 {
+    const FIELD: MatrixMarketField = MatrixMarketField::Complex;
+
+    fn parse_mtx(field: MatrixMarketField, tokens: &[&str]) -> Result<Self, String> {
+        let (re, im) = parse_value(field, tokens)?;
+
+        Ok(c64::new(re, im))
+    }
+
+    fn format_mtx(&self) -> String {
+        format!("{} {}", self.re, self.im)
+    }
+
+    fn conj_mtx(self) -> Self {
+        self.conj()
+    }
+}
+
+/// Entries exactly as stored in the file, with zero-based indices.
+struct MatrixMarketData<T> {
+    header: MatrixMarketHeader,
+    rows: usize,
+    cols: usize,
+    size_line: usize,
+    entries: Vec<((usize, usize), T)>,
+}
+
+impl<T> MatrixMarketData<T>
+where
+    T: MatrixMarketElement,
+{
+    /// Checks that `len` dense elements can be addressed before allocating them.
+    fn check_dense_len(&self, len: Option<usize>) -> Result<(), MatrixError> {
+        len.map(|_| ())
+            .ok_or_else(|| parse_error(self.size_line, "size is too large for dense storage"))
+    }
+
+    /// Calls `f` for every stored entry and for the entries implied by the symmetry.
+    fn for_each_expanded(self, mut f: impl FnMut((usize, usize), T)) {
+        let symmetry = self.header.symmetry;
+
+        for ((i, j), value) in self.entries {
+            f((i, j), value);
+
+            if i == j {
+                continue;
+            }
+            match symmetry {
+                MatrixMarketSymmetry::General => {}
+                MatrixMarketSymmetry::Symmetric => f((j, i), value),
+                MatrixMarketSymmetry::SkewSymmetric => f((j, i), -value),
+                MatrixMarketSymmetry::Hermitian => f((j, i), value.conj_mtx()),
+            }
+        }
+    }
+}
+
+const RESERVE_LIMIT: usize = 1 << 16;
+
+fn parse_error(line: usize, message: impl Into<String>) -> MatrixError {
+    MatrixError::ParseError {
+        line,
+        message: message.into(),
+    }
+}
+
+fn parse_size(token: &str) -> Result<usize, String> {
+    token
+        .parse::<usize>()
+        .map_err(|_| format!("`{}` is not a size", token))
+}
+
+fn parse_index(token: &str, dim: usize) -> Result<usize, String> {
+    let index = parse_size(token)?;
+    if index == 0 || dim < index {
+        return Err(format!("index {} is out of range 1..={}", index, dim));
+    }
+
+    Ok(index - 1)
+}
+
+fn read_mtx<T, R>(reader: R) -> Result<MatrixMarketData<T>, MatrixError>
+where
+    T: MatrixMarketElement,
+    R: Read,
+{
+    let mut lines = BufReader::new(reader)
+        .lines()
+        .enumerate()
+        .map(|(k, text)| (k + 1, text));
+
+    let header = match lines.next() {
+        Some((line, text)) => {
+            MatrixMarketHeader::parse(&text?).map_err(|m| parse_error(line, m))?
+        }
+        None => return Err(parse_error(1, "input is empty")),
+    };
+
+    let mut size = None;
+    let mut size_line = 1;
+    let mut entries = vec![];
+    let mut cursor = (0usize, 0usize);
+    let mut last_line = 1;
+
+    for (line, text) in lines {
+        let text = text?;
+        last_line = line;
+
+        let trimmed = text.trim();
+        if trimmed.is_empty() || trimmed.starts_with('%') {
+            continue;
+        }
+        let tokens = trimmed.split_whitespace().collect::<Vec<_>>();
+
+        let (rows, cols, len) = match size {
+            Some(size) => size,
+            None => {
+                let expected = match header.format {
+                    MatrixMarketFormat::Coordinate => 3,
+                    MatrixMarketFormat::Array => 2,
+                };
+                if tokens.len() != expected {
+                    return Err(parse_error(
+                        line,
+                        format!("{} sizes are expected", expected),
+                    ));
+                }
+
+                let sizes = tokens
+                    .iter()
+                    .map(|t| parse_size(t))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|m| parse_error(line, m))?;
+                let (rows, cols) = (sizes[0], sizes[1]);
+
+                if header.symmetry != MatrixMarketSymmetry::General && rows != cols {
+                    return Err(parse_error(
+                        line,
+                        "symmetric storage requires a square matrix",
+                    ));
+                }
+
+                let len = match (header.format, header.symmetry) {
+                    (MatrixMarketFormat::Coordinate, _) => Some(sizes[2]),
+                    (MatrixMarketFormat::Array, MatrixMarketSymmetry::General) => {
+                        rows.checked_mul(cols)
+                    }
+                    (MatrixMarketFormat::Array, MatrixMarketSymmetry::SkewSymmetric) => {
+                        rows.checked_mul(rows.max(1) - 1).map(|n| n / 2)
+                    }
+                    (MatrixMarketFormat::Array, _) => rows
+                        .checked_add(1)
+                        .and_then(|n| rows.checked_mul(n))
+                        .map(|n| n / 2),
+                }
+                .ok_or_else(|| parse_error(line, "size is too large"))?;
+
+                // The size line is not trusted, so the rest grows as the entries are read.
+                entries.reserve(len.min(RESERVE_LIMIT));
+                cursor = (header.symmetry.first_row(0), 0);
+                size = Some((rows, cols, len));
+                size_line = line;
+                continue;
+            }
+        };
+
+        if entries.len() == len {
+            return Err(parse_error(line, format!("more than {} entries", len)));
+        }
+
+        let (index, value_tokens) = match header.format {
+            MatrixMarketFormat::Coordinate => {
+                if tokens.len() < 2 {
+                    return Err(parse_error(line, "row and column indices are expected"));
+                }
+                let i = parse_index(tokens[0], rows).map_err(|m| parse_error(line, m))?;
+                let j = parse_index(tokens[1], cols).map_err(|m| parse_error(line, m))?;
+
+                ((i, j), &tokens[2..])
+            }
+            MatrixMarketFormat::Array => {
+                let index = cursor;
+                cursor.0 += 1;
+                while rows <= cursor.0 && cursor.1 < cols {
+                    cursor.1 += 1;
+                    cursor.0 = header.symmetry.first_row(cursor.1);
+                }
+
+                (index, &tokens[..])
+            }
+        };
+
+        let value = T::parse_mtx(header.field, value_tokens).map_err(|m| parse_error(line, m))?;
+        entries.push((index, value));
+    }
+
+    match size {
+        Some((rows, cols, len)) => {
+            if entries.len() != len {
+                return Err(parse_error(
+                    last_line,
+                    format!("{} entries are expected but found {}", len, entries.len()),
+                ));
+            }
+
+            Ok(MatrixMarketData {
+                header,
+                rows,
+                cols,
+                size_line,
+                entries,
+            })
+        }
+        None => Err(parse_error(last_line, "size line is missing")),
+    }
+}
+
+impl<T> SparseMatrix<T>
+where
+    T: MatrixMarketElement,
+{
+    /// # Read Matrix Market
+    /// Entries omitted by symmetric, skew-symmetric or hermitian storage are restored.
+    /// Zeros in array format are not stored.
+    pub fn read_mtx<R: Read>(reader: R) -> Result<Self, MatrixError> {
+        let data = read_mtx::<T, R>(reader)?;
+        let array = data.header.format == MatrixMarketFormat::Array;
+        let mut mat = SparseMatrix::new(data.rows, data.cols);
+
+        data.for_each_expanded(|index, value| {
+            if array && value == T::default() {
+                return;
+            }
+            *mat.elems.entry(index).or_default() += value;
+        });
+
+        Ok(mat)
+    }
+
+    /// # Write Matrix Market
+    /// in coordinate format.
+    /// Except for `General`, only the lower triangle is written.
+    pub fn write_mtx<W: Write>(
+        &self,
+        writer: W,
+        symmetry: MatrixMarketSymmetry,
+    ) -> Result<(), MatrixError> {
+        if symmetry != MatrixMarketSymmetry::General && self.rows != self.cols {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let mut entries = self
+            .elems
+            .iter()
+            .filter(|(&(i, j), _)| symmetry.stores(i, j))
+            .collect::<Vec<_>>();
+        entries.sort_by_key(|(&(i, j), _)| (j, i));
+
+        let header = MatrixMarketHeader {
+            format: MatrixMarketFormat::Coordinate,
+            field: T::FIELD,
+            symmetry,
+        };
+
+        let mut writer = BufWriter::new(writer);
+        writeln!(writer, "{}", header)?;
+        writeln!(writer, "{} {} {}", self.rows, self.cols, entries.len())?;
+        for (&(i, j), value) in entries {
+            writeln!(writer, "{} {} {}", i + 1, j + 1, value.format_mtx())?;
+        }
+        writer.flush()?;
+
+        Ok(())
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: MatrixMarketElement,
+{
+    /// # Read Matrix Market
+    /// in either coordinate or array format.
+    /// Entries omitted by symmetric, skew-symmetric or hermitian storage are restored.
+    pub fn read_mtx<R: Read>(reader: R) -> Result<Self, MatrixError> {
+        let data = read_mtx::<T, R>(reader)?;
+        data.check_dense_len(data.rows.checked_mul(data.cols))?;
+        let mut mat = Matrix::new(data.rows, data.cols);
+
+        data.for_each_expanded(|index, value| mat[index] += value);
+
+        Ok(mat)
+    }
+
+    /// # Write Matrix Market
+    /// in array format.
+    /// Except for `General`, only the lower triangle is written.
+    pub fn write_mtx<W: Write>(
+        &self,
+        writer: W,
+        symmetry: MatrixMarketSymmetry,
+    ) -> Result<(), MatrixError> {
+        if symmetry != MatrixMarketSymmetry::General && self.rows() != self.cols() {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let header = MatrixMarketHeader {
+            format: MatrixMarketFormat::Array,
+            field: T::FIELD,
+            symmetry,
+        };
+
+        let mut writer = BufWriter::new(writer);
+        writeln!(writer, "{}", header)?;
+        writeln!(writer, "{} {}", self.rows(), self.cols())?;
+        for j in 0..self.cols() {
+            for i in symmetry.first_row(j)..self.rows() {
+                writeln!(writer, "{}", self[(i, j)].format_mtx())?;
+            }
+        }
+        writer.flush()?;
+
+        Ok(())
+    }
+}
+
+impl<T> SymmetricPackedMatrix<T>
+where
+    T: MatrixMarketElement,
+{
+    /// # Read Matrix Market
+    /// The file must be symmetric or hermitian.
+    /// Entries given in the upper triangle are moved to the lower triangle.
+    pub fn read_mtx<R: Read>(reader: R) -> Result<Self, MatrixError> {
+        let data = read_mtx::<T, R>(reader)?;
+        let hermitian = match data.header.symmetry {
+            MatrixMarketSymmetry::Symmetric => false,
+            MatrixMarketSymmetry::Hermitian => true,
+            _ => return Err(parse_error(1, "symmetric or hermitian matrix is expected")),
+        };
+
+        let n = data.rows;
+        data.check_dense_len(n.checked_add(1).and_then(|m| n.checked_mul(m)))?;
+        let mut packed = SymmetricPackedMatrix::new(n);
+        let elems = packed.elems_mut();
+
+        for ((i, j), value) in data.entries {
+            let (i, j, value) = if j <= i {
+                (i, j, value)
+            } else if hermitian {
+                (j, i, value.conj_mtx())
+            } else {
+                (j, i, value)
+            };
+            elems[j * (2 * n - j + 1) / 2 + i - j] += value;
+        }
+
+        Ok(packed)
+    }
+
+    /// # Write Matrix Market
+    /// in array format with symmetric or hermitian storage.
+    pub fn write_mtx<W: Write>(&self, writer: W, hermitian: bool) -> Result<(), MatrixError> {
+        let header = MatrixMarketHeader {
+            format: MatrixMarketFormat::Array,
+            field: T::FIELD,
+            symmetry: if hermitian {
+                MatrixMarketSymmetry::Hermitian
+            } else {
+                MatrixMarketSymmetry::Symmetric
+            },
+        };
+
+        let mut writer = BufWriter::new(writer);
+        writeln!(writer, "{}", header)?;
+        writeln!(writer, "{} {}", self.dim(), self.dim())?;
+        for value in self.elems() {
+            writeln!(writer, "{}", value.format_mtx())?;
+        }
+        writer.flush()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn it_works() {
+        let text = "%%MatrixMarket matrix coordinate real symmetric
+% comment
+3 3 3
+1 1 2.0
+3 1 -1.5
+3 3 4
+";
+        let a = SparseMatrix::<f64>::read_mtx(text.as_bytes()).unwrap();
+
+        assert_eq!(a.elems.len(), 4);
+        assert_eq!(a[(2, 0)], -1.5);
+        assert_eq!(a[(0, 2)], -1.5);
+        assert_eq!(a[(2, 2)], 4.0);
+
+        let mut buf = vec![];
+        a.write_mtx(&mut buf, MatrixMarketSymmetry::Symmetric)
+            .unwrap();
+        let b = SparseMatrix::<f64>::read_mtx(buf.as_slice()).unwrap();
+
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn array() {
+        let a = mat!(
+            1.0, 2.0, 3.0;
+            4.0, 5.0, 6.0
+        );
+        let mut buf = vec![];
+        a.write_mtx(&mut buf, MatrixMarketSymmetry::General)
+            .unwrap();
+        let b = Matrix::<f64>::read_mtx(buf.as_slice()).unwrap();
+
+        assert_eq!(a, b);
+
+        let text = "%%MatrixMarket matrix array real skew-symmetric
+3 3
+1
+2
+3
+";
+        let c = Matrix::<f64>::read_mtx(text.as_bytes()).unwrap();
+
+        assert_eq!(c[(1, 0)], 1.0);
+        assert_eq!(c[(0, 1)], -1.0);
+        assert_eq!(c[(2, 1)], 3.0);
+        assert_eq!(c[(1, 2)], -3.0);
+    }
+
+    #[test]
+    fn hermitian() {
+        let text = "%%MatrixMarket matrix coordinate complex hermitian
+2 2 3
+1 1 1.0 0.0
+2 1 2.0 3.0
+2 2 4.0 0.0
+";
+        let a = Matrix::<c64>::read_mtx(text.as_bytes()).unwrap();
+
+        assert_eq!(a[(1, 0)], c64::new(2.0, 3.0));
+        assert_eq!(a[(0, 1)], c64::new(2.0, -3.0));
+
+        let ap = SymmetricPackedMatrix::<c64>::read_mtx(text.as_bytes()).unwrap();
+
+        assert_eq!(
+            ap.elems(),
+            [c64::new(1.0, 0.0), c64::new(2.0, 3.0), c64::new(4.0, 0.0)]
+        );
+
+        let mut buf = vec![];
+        ap.write_mtx(&mut buf, true).unwrap();
+        let bp = SymmetricPackedMatrix::<c64>::read_mtx(buf.as_slice()).unwrap();
+
+        assert_eq!(ap, bp);
+    }
+
+    #[test]
+    fn parse_error() {
+        let text = "%%MatrixMarket matrix coordinate real general
+2 2 2
+1 1 1.0
+3 1 2.0
+";
+        match Matrix::<f64>::read_mtx(text.as_bytes()) {
+            Err(MatrixError::ParseError { line, .. }) => assert_eq!(line, 4),
+            _ => panic!(),
+        }
+
+        let text = "%%MatrixMarket matrix coordinate real general
+1 1 99999999999
+1 1 1.0
+";
+        match Matrix::<f64>::read_mtx(text.as_bytes()) {
+            Err(MatrixError::ParseError { line, .. }) => assert_eq!(line, 3),
+            _ => panic!(),
+        }
+
+        let text = format!(
+            "%%MatrixMarket matrix array real general\n{} {}\n",
+            usize::MAX,
+            2
+        );
+        match Matrix::<f64>::read_mtx(text.as_bytes()) {
+            Err(MatrixError::ParseError { line, .. }) => assert_eq!(line, 2),
+            _ => panic!(),
+        }
+
+        let text = format!(
+            "%%MatrixMarket matrix coordinate real symmetric\n% comment\n{} {} 1\n1 1 1.0\n",
+            usize::MAX,
+            usize::MAX
+        );
+        match Matrix::<f64>::read_mtx(text.as_bytes()) {
+            Err(MatrixError::ParseError { line, .. }) => assert_eq!(line, 3),
+            _ => panic!(),
+        }
+        match SymmetricPackedMatrix::<f64>::read_mtx(text.as_bytes()) {
+            Err(MatrixError::ParseError { line, .. }) => assert_eq!(line, 3),
+            _ => panic!(),
+        }
+        assert!(SparseMatrix::<f64>::read_mtx(text.as_bytes()).is_ok());
+    }
+}
@@ -0,0 +1,599 @@
+use crate::{number::c64, Matrix, MatrixError, Number};
+use rayon::prelude::*;
+use std::{
+    convert::TryInto,
+    io::{BufWriter, Read, Seek, Write},
+};
+use zip::{write::FileOptions, CompressionMethod, ZipArchive, ZipWriter};
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+/// Element types which can be read from and written to `.npy` files.
+pub trait NpyElement: Number {
+    /// Type code without byte order, such as `f8`.
+    const KIND: &'static str;
+    /// Bytes per element.
+    const SIZE: usize;
+
+    fn from_npy_bytes(bytes: &[u8], big_endian: bool) -> Self;
+    /// Little endian bytes.
+    fn to_npy_bytes(&self) -> Vec<u8>;
+}
+
+fn f64_from_bytes(bytes: &[u8], big_endian: bool) -> f64 {
+    let bytes = bytes.try_into().unwrap();
+    if big_endian {
+        f64::from_be_bytes(bytes)
+    } else {
+        f64::from_le_bytes(bytes)
+    }
+}
+
+impl NpyElement for f64 {
+    const KIND: &'static str = "f8";
+    const SIZE: usize = 8;
+
+    fn from_npy_bytes(bytes: &[u8], big_endian: bool) -> Self {
+        f64_from_bytes(bytes, big_endian)
+    }
+
+    fn to_npy_bytes(&self) -> Vec<u8> {
+        self.to_le_bytes().to_vec()
+    }
+}
+
+impl NpyElement for c64 {
+    const KIND: &'static str = "c16";
+    const SIZE: usize = 16;
+
+    fn from_npy_bytes(bytes: &[u8], big_endian: bool) -> Self {
+        c64::new(
+            f64_from_bytes(&bytes[0..8], big_endian),
+            f64_from_bytes(&bytes[8..16], big_endian),
+        )
+    }
+
+    fn to_npy_bytes(&self) -> Vec<u8> {
+        [self.re.to_le_bytes(), self.im.to_le_bytes()].concat()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct NpyHeader {
+    descr: String,
+    fortran_order: bool,
+    shape: Vec<usize>,
+}
+
+/// The header of npy is a single line, so parse errors are reported as line 1.
+fn header_error(message: impl Into<String>) -> MatrixError {
+    MatrixError::ParseError {
+        line: 1,
+        message: message.into(),
+    }
+}
+
+/// Returns the text following `'key':` in a python dict literal.
+fn dict_value<'a>(dict: &'a str, key: &str) -> Result<&'a str, MatrixError> {
+    let quoted = [format!("'{}'", key), format!("\"{}\"", key)];
+    let start = quoted
+        .iter()
+        .filter_map(|q| dict.find(q.as_str()).map(|i| i + q.len()))
+        .next()
+        .ok_or_else(|| header_error(format!("`{}` is missing", key)))?;
+    let rest = dict[start..].trim_start();
+
+    match rest.strip_prefix(':') {
+        Some(rest) => Ok(rest.trim_start()),
+        None => Err(header_error(format!("`:` is expected after `{}`", key))),
+    }
+}
+
+impl NpyHeader {
+    fn parse(dict: &str) -> Result<Self, MatrixError> {
+        let descr = dict_value(dict, "descr")?;
+        let quote = descr
+            .chars()
+            .next()
+            .filter(|&c| c == '\'' || c == '"')
+            .ok_or_else(|| header_error("`descr` must be a string"))?;
+        let descr = descr[1..]
+            .split(quote)
+            .next()
+            .unwrap_or_default()
+            .to_owned();
+
+        let fortran_order = dict_value(dict, "fortran_order")?;
+        let fortran_order = if fortran_order.starts_with("True") {
+            true
+        } else if fortran_order.starts_with("False") {
+            false
+        } else {
+            return Err(header_error("`fortran_order` must be a bool"));
+        };
+
+        let shape = dict_value(dict, "shape")?;
+        let shape = shape
+            .strip_prefix('(')
+            .and_then(|s| s.split(')').next())
+            .ok_or_else(|| header_error("`shape` must be a tuple"))?
+            .split(',')
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+            .map(|s| {
+                s.trim_end_matches('L')
+                    .parse::<usize>()
+                    .map_err(|_| header_error(format!("`{}` is not a size", s)))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            descr,
+            fortran_order,
+            shape,
+        })
+    }
+
+    fn to_dict(&self) -> String {
+        let shape = match self.shape.len() {
+            1 => format!("({},)", self.shape[0]),
+            _ => format!(
+                "({})",
+                self.shape
+                    .iter()
+                    .map(|d| d.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        };
+
+        format!(
+            "{{'descr': '{}', 'fortran_order': {}, 'shape': {}, }}",
+            self.descr,
+            if self.fortran_order { "True" } else { "False" },
+            shape
+        )
+    }
+}
+
+fn read_header<R: Read>(reader: &mut R) -> Result<NpyHeader, MatrixError> {
+    let mut preamble = [0u8; 8];
+    reader.read_exact(&mut preamble)?;
+    if &preamble[0..6] != MAGIC {
+        return Err(header_error("npy magic string is not found"));
+    }
+
+    let len = match preamble[6] {
+        1 => {
+            let mut len = [0u8; 2];
+            reader.read_exact(&mut len)?;
+            u16::from_le_bytes(len) as usize
+        }
+        2 | 3 => {
+            let mut len = [0u8; 4];
+            reader.read_exact(&mut len)?;
+            u32::from_le_bytes(len) as usize
+        }
+        major => {
+            return Err(header_error(format!(
+                "npy version {} is not supported",
+                major
+            )))
+        }
+    };
+
+    let mut dict = vec![0u8; len];
+    reader.read_exact(&mut dict)?;
+    let dict = String::from_utf8(dict).map_err(|_| header_error("header is not utf-8"))?;
+
+    NpyHeader::parse(&dict)
+}
+
+/// Returns `(shape, elems)` with elems in Fortran order.
+fn read_array<T, R>(reader: R) -> Result<(Vec<usize>, Vec<T>), MatrixError>
+where
+    T: NpyElement,
+    R: Read,
+{
+    let mut reader = reader;
+    let header = read_header(&mut reader)?;
+
+    let (order, kind) = header.descr.split_at(1.min(header.descr.len()));
+    let (big_endian, kind) = match order {
+        "<" | "|" => (false, kind),
+        ">" => (true, kind),
+        "=" => (cfg!(target_endian = "big"), kind),
+        _ => (false, header.descr.as_str()),
+    };
+    if kind != T::KIND {
+        return Err(header_error(format!(
+            "dtype `{}` can not be read as `{}`",
+            header.descr,
+            T::KIND
+        )));
+    }
+
+    let len = header
+        .shape
+        .iter()
+        .try_fold(1usize, |acc, &d| acc.checked_mul(d))
+        .ok_or_else(|| header_error("shape is too large"))?;
+    let size = len
+        .checked_mul(T::SIZE)
+        .ok_or_else(|| header_error("shape is too large"))?;
+
+    // The buffer grows as the data arrives, so a corrupt shape can not request a huge allocation up front.
+    let mut bytes = vec![];
+    reader.take(size as u64).read_to_end(&mut bytes)?;
+    if bytes.len() != size {
+        return Err(header_error(format!(
+            "{} bytes of data are expected but found {}",
+            size,
+            bytes.len()
+        )));
+    }
+
+    let elems = bytes
+        .par_chunks(T::SIZE)
+        .map(|b| T::from_npy_bytes(b, big_endian))
+        .collect::<Vec<_>>();
+
+    if header.fortran_order || header.shape.len() < 2 {
+        return Ok((header.shape, elems));
+    }
+
+    // Reverse the axes of a C order array.
+    let shape = header.shape;
+    let mut strides = vec![1usize; shape.len()];
+    for k in (0..shape.len() - 1).rev() {
+        strides[k] = strides[k + 1] * shape[k + 1];
+    }
+    let elems = (0..len)
+        .into_par_iter()
+        .map(|f| {
+            let mut f = f;
+            let mut c = 0;
+            for (&d, &s) in shape.iter().zip(strides.iter()) {
+                c += (f % d) * s;
+                f /= d;
+            }
+            elems[c]
+        })
+        .collect();
+
+    Ok((shape, elems))
+}
+
+/// Writes the preamble and the header in format version 1.0, or 2.0 if the header is longer than 65535 bytes.
+fn write_header<W: Write>(writer: &mut W, header: &NpyHeader) -> Result<(), MatrixError> {
+    // magic, version, length and dict end with a newline at a multiple of 64 bytes.
+    let padded = |len_bytes: usize| {
+        let mut dict = header.to_dict();
+        let unpadded = MAGIC.len() + 2 + len_bytes + dict.len() + 1;
+        dict.push_str(&" ".repeat((64 - unpadded % 64) % 64));
+        dict.push('\n');
+        dict
+    };
+
+    let dict = padded(2);
+    if dict.len() <= u16::MAX as usize {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[1, 0])?;
+        writer.write_all(&(dict.len() as u16).to_le_bytes())?;
+        writer.write_all(dict.as_bytes())?;
+
+        return Ok(());
+    }
+
+    let dict = padded(4);
+    let len: u32 = dict
+        .len()
+        .try_into()
+        .map_err(|_| header_error("header is too long"))?;
+    writer.write_all(MAGIC)?;
+    writer.write_all(&[2, 0])?;
+    writer.write_all(&len.to_le_bytes())?;
+    writer.write_all(dict.as_bytes())?;
+
+    Ok(())
+}
+
+/// Writes elems in Fortran order.
+fn write_array<T, W>(writer: W, shape: &[usize], elems: &[T]) -> Result<(), MatrixError>
+where
+    T: NpyElement,
+    W: Write,
+{
+    let header = NpyHeader {
+        descr: format!("<{}", T::KIND),
+        fortran_order: 1 < shape.len(),
+        shape: shape.to_vec(),
+    };
+
+    let mut writer = BufWriter::new(writer);
+    write_header(&mut writer, &header)?;
+
+    let bytes = elems
+        .par_iter()
+        .flat_map_iter(|e| e.to_npy_bytes())
+        .collect::<Vec<_>>();
+    writer.write_all(&bytes)?;
+    writer.flush()?;
+
+    Ok(())
+}
+
+impl<T> Matrix<T>
+where
+    T: NpyElement,
+{
+    /// # Read npy
+    /// Both Fortran order and C order arrays are accepted.
+    /// A 1-dimensional array becomes a column vector.
+    pub fn read_npy<R: Read>(reader: R) -> Result<Self, MatrixError> {
+        let (shape, elems) = read_array::<T, R>(reader)?;
+        let (rows, cols) = match shape.len() {
+            1 => (shape[0], 1),
+            2 => (shape[0], shape[1]),
+            _ => return Err(MatrixError::DimensionMismatch),
+        };
+
+        if rows == 0 {
+            return Ok(Matrix::new(rows, cols));
+        }
+
+        Matrix::from(rows, elems)
+    }
+
+    /// # Write npy
+    /// in Fortran order so that the elements are written as they are.
+    pub fn write_npy<W: Write>(&self, writer: W) -> Result<(), MatrixError> {
+        write_array(writer, &[self.rows(), self.cols()], self.elems())
+    }
+}
+
+/// # Read npy
+/// as a dense vector. The array must be 1-dimensional.
+pub fn read_npy_vec<T, R>(reader: R) -> Result<Vec<T>, MatrixError>
+where
+    T: NpyElement,
+    R: Read,
+{
+    let (shape, elems) = read_array::<T, R>(reader)?;
+    if shape.len() != 1 {
+        return Err(MatrixError::DimensionMismatch);
+    }
+
+    Ok(elems)
+}
+
+/// # Write npy
+/// as a 1-dimensional array.
+pub fn write_npy_vec<T, W>(v: &[T], writer: W) -> Result<(), MatrixError>
+where
+    T: NpyElement,
+    W: Write,
+{
+    write_array(writer, &[v.len()], v)
+}
+
+/// Reader of `.npz` archives made by `numpy.savez` or `numpy.savez_compressed`.
+pub struct NpzReader<R>
+where
+    R: Read + Seek,
+{
+    archive: ZipArchive<R>,
+}
+
+impl<R> NpzReader<R>
+where
+    R: Read + Seek,
+{
+    pub fn new(reader: R) -> Result<Self, MatrixError> {
+        Ok(Self {
+            archive: ZipArchive::new(reader)?,
+        })
+    }
+
+    /// Names of the arrays without `.npy`.
+    pub fn names(&self) -> Vec<String> {
+        self.archive
+            .file_names()
+            .map(|name| name.trim_end_matches(".npy").to_owned())
+            .collect()
+    }
+
+    pub fn matrix<T>(&mut self, name: &str) -> Result<Matrix<T>, MatrixError>
+    where
+        T: NpyElement,
+    {
+        let file = self.archive.by_name(&format!("{}.npy", name))?;
+
+        Matrix::read_npy(file)
+    }
+
+    pub fn vec<T>(&mut self, name: &str) -> Result<Vec<T>, MatrixError>
+    where
+        T: NpyElement,
+    {
+        let file = self.archive.by_name(&format!("{}.npy", name))?;
+
+        read_npy_vec(file)
+    }
+}
+
+/// Writer of `.npz` archives readable by `numpy.load`.
+pub struct NpzWriter<W>
+where
+    W: Write + Seek,
+{
+    zip: ZipWriter<W>,
+    options: FileOptions,
+}
+
+impl<W> NpzWriter<W>
+where
+    W: Write + Seek,
+{
+    /// Arrays are stored without compression like `numpy.savez`.
+    pub fn new(writer: W) -> Self {
+        Self {
+            zip: ZipWriter::new(writer),
+            options: FileOptions::default().compression_method(CompressionMethod::Stored),
+        }
+    }
+
+    /// Arrays are deflated like `numpy.savez_compressed`.
+    pub fn new_compressed(writer: W) -> Self {
+        Self {
+            zip: ZipWriter::new(writer),
+            options: FileOptions::default().compression_method(CompressionMethod::Deflated),
+        }
+    }
+
+    pub fn add_matrix<T>(&mut self, name: &str, mat: &Matrix<T>) -> Result<(), MatrixError>
+    where
+        T: NpyElement,
+    {
+        self.zip.start_file(format!("{}.npy", name), self.options)?;
+
+        mat.write_npy(&mut self.zip)
+    }
+
+    pub fn add_vec<T>(&mut self, name: &str, v: &[T]) -> Result<(), MatrixError>
+    where
+        T: NpyElement,
+    {
+        self.zip.start_file(format!("{}.npy", name), self.options)?;
+
+        write_npy_vec(v, &mut self.zip)
+    }
+
+    /// Writes the central directory and returns the inner writer.
+    pub fn finish(self) -> Result<W, MatrixError> {
+        let mut zip = self.zip;
+
+        Ok(zip.finish()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn it_works() {
+        let a = mat!(
+            1.0, 2.0, 3.0;
+            4.0, 5.0, 6.0
+        );
+        let mut buf = vec![];
+        a.write_npy(&mut buf).unwrap();
+
+        assert_eq!(buf.len() % 64, 6 * 8);
+
+        let b = Matrix::<f64>::read_npy(buf.as_slice()).unwrap();
+
+        assert_eq!(a, b);
+
+        let c: Matrix<c64> = a.into();
+        let mut buf = vec![];
+        c.write_npy(&mut buf).unwrap();
+        let d = Matrix::<c64>::read_npy(buf.as_slice()).unwrap();
+
+        assert_eq!(c, d);
+    }
+
+    #[test]
+    fn c_order() {
+        let header = NpyHeader {
+            descr: "<f8".to_owned(),
+            fortran_order: false,
+            shape: vec![2, 3],
+        };
+        let mut buf = vec![];
+        write_header(&mut buf, &header).unwrap();
+        for e in 1..=6 {
+            buf.extend_from_slice(&(e as f64).to_le_bytes());
+        }
+
+        let a = Matrix::<f64>::read_npy(buf.as_slice()).unwrap();
+
+        assert_eq!(
+            a,
+            mat!(
+                1.0, 2.0, 3.0;
+                4.0, 5.0, 6.0
+            )
+        );
+    }
+
+    #[test]
+    fn corrupt_header() {
+        let header = NpyHeader {
+            descr: "<f8".to_owned(),
+            fortran_order: true,
+            shape: vec![usize::MAX, 2],
+        };
+        let mut buf = vec![];
+        write_header(&mut buf, &header).unwrap();
+
+        match Matrix::<f64>::read_npy(buf.as_slice()) {
+            Err(MatrixError::ParseError { .. }) => {}
+            _ => panic!(),
+        }
+
+        let header = NpyHeader {
+            shape: vec![1 << 40, 1],
+            ..header
+        };
+        let mut buf = vec![];
+        write_header(&mut buf, &header).unwrap();
+        buf.extend_from_slice(&1.0f64.to_le_bytes());
+
+        match Matrix::<f64>::read_npy(buf.as_slice()) {
+            Err(MatrixError::ParseError { .. }) => {}
+            _ => panic!(),
+        }
+    }
+
+    #[test]
+    fn long_header() {
+        let shape = vec![1; 30000];
+        let mut buf = vec![];
+        write_array(&mut buf, &shape, &[2.0]).unwrap();
+
+        assert_eq!(buf[6], 2);
+        assert_eq!(buf.len() % 64, 8);
+
+        let (read_shape, elems) = read_array::<f64, _>(buf.as_slice()).unwrap();
+
+        assert_eq!(read_shape, shape);
+        assert_eq!(elems, vec![2.0]);
+    }
+
+    #[test]
+    fn npz() {
+        let a = mat!(
+            1.0, 2.0;
+            3.0, 4.0
+        );
+        let v = vec![c64::new(1.0, 2.0), c64::new(3.0, 4.0)];
+
+        let mut writer = NpzWriter::new_compressed(Cursor::new(vec![]));
+        writer.add_matrix("a", &a).unwrap();
+        writer.add_vec("v", &v).unwrap();
+        let buf = writer.finish().unwrap();
+
+        let mut reader = NpzReader::new(buf).unwrap();
+        let mut names = reader.names();
+        names.sort();
+
+        assert_eq!(names, vec!["a", "v"]);
+        assert_eq!(reader.matrix::<f64>("a").unwrap(), a);
+        assert_eq!(reader.vec::<c64>("v").unwrap(), v);
+        assert!(reader.matrix::<c64>("a").is_err());
+    }
+}
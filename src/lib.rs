@@ -7,12 +7,15 @@ extern crate lapack_src;
 extern crate rand;
 extern crate rayon;
 extern crate thiserror;
+extern crate zip;
 
+pub mod io;
 pub mod macros;
 pub mod matrix;
 pub mod number;
 pub mod tensor;
 
+pub use io::*;
 pub use matrix::*;
 pub use number::*;
 pub use tensor::*;
@@ -1,56 +1,81 @@
-use crate::matrix::ci::CirculantMatrix;
-use crate::{number::c64, Matrix};
+use crate::matrix::ci::{fourier::FourierMatrix, CirculantMatrix};
+use crate::number::c64;
 use rayon::prelude::*;
 use rustfft::FftPlanner;
-use std::f64::consts::PI;
-
-impl CirculantMatrix<f64> {
-    pub fn cievd(&self) -> (Matrix<c64>, Vec<c64>) {
-        let row_elems = self.row_elems();
-        let n = row_elems.len();
-
-        let mut fourier_matrix: Matrix<c64> = Matrix::<c64>::new(n, n);
-        let omega = c64::new(0.0, 2.0 * PI / (n as f64)).exp();
-
-        // for i in 0..n {
-        //     for j in 0..i {
-        //         fourier_matrix[i][j] = fourier_matrix[j][i];
-        //     }
-        //     for j in i..n {
-        //         fourier_matrix[i][j] = omega.powi((i * j) as i32);
-        //     }
-        // }
-
-        fourier_matrix
-            .elems_mut()
-            .par_iter_mut()
-            .enumerate()
-            .map(|(k, elem)| ((k / n, k % n), elem))
-            .for_each(|((i, j), elem)| {
-                *elem = omega.powi((i * j) as i32);
-            });
-
-        let mut buffer = row_elems
-            .par_iter()
-            .map(|&e| c64::new(e, 0.0))
-            .collect::<Vec<c64>>();
-
-        let mut planner = FftPlanner::new();
-        let fft = planner.plan_fft_forward(n);
-        fft.process(&mut buffer);
-
-        (fourier_matrix, buffer)
-    }
+
+macro_rules! impl_circulant_evd {
+    {$t: ty, $to_c64: expr} => {
+        impl CirculantMatrix<$t> {
+            /// # Eigenvalues
+            /// `lambda = F * c` by FFT, where `c` is the first column.
+            ///
+            /// `lambda[k]` belongs to the column `k` of `FourierMatrix`.
+            pub fn eigenvalues(&self) -> Vec<c64> {
+                let mut buffer = self
+                    .col_elems()
+                    .par_iter()
+                    .map(|&e| $to_c64(e))
+                    .collect::<Vec<c64>>();
+                let n = buffer.len();
+                if n == 0 {
+                    return buffer;
+                }
+
+                let mut planner = FftPlanner::new();
+                let fft = planner.plan_fft_forward(n);
+                fft.process(&mut buffer);
+
+                buffer
+            }
+
+            /// # Eigen decomposition
+            /// `C = U * diag(lambda) * U^H` with the implicit unitary Fourier matrix `U`.
+            ///
+            /// `(u, lambda)`
+            pub fn cievd(&self) -> (FourierMatrix, Vec<c64>) {
+                (FourierMatrix::new(self.col_elems().len()), self.eigenvalues())
+            }
+
+            /// # Log determinant
+            /// `ln|det(C)| = Σ_k ln|lambda_k|`
+            pub fn logdet(&self) -> f64 {
+                self.eigenvalues().iter().map(|l| l.norm().ln()).sum()
+            }
+        }
+    };
 }
 
+impl_circulant_evd! {f64, |e: f64| c64::new(e, 0.0)}
+impl_circulant_evd! {c64, |e: c64| e}
+
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use crate::matrix::ge::trf::GETRF;
+    use crate::*;
     #[test]
     fn it_works() {
         let a = CirculantMatrix::new(vec![1.0, 2.0, 3.0]);
         let diagonalized = a.cievd();
 
         assert_eq!(diagonalized.1[0].re, 6.0);
+
+        let a = CirculantMatrix::new(vec![1.0, 2.0, -3.0, 0.5]);
+        let dense = a.mat();
+        let (u, lambda) = a.cievd();
+        let u = u.mat();
+        let ulu = u
+            .clone()
+            .scale_cols(&DiagonalMatrix::new(lambda))
+            .unwrap()
+            .dot(&u.adjoint());
+
+        for k in 0..16 {
+            assert!((ulu.elems()[k] - c64::new(dense.elems()[k], 0.0)).norm() < 1e-12);
+        }
+
+        let GETRF(lu, _) = a.mat().getrf().unwrap();
+        let logdet = (0..4).map(|i| lu[(i, i)].abs().ln()).sum::<f64>();
+
+        assert!((a.logdet() - logdet).abs() < 1e-12);
     }
 }
@@ -0,0 +1,109 @@
+use crate::{matrix::MatrixError, number::c64, Matrix};
+use rayon::prelude::*;
+use rustfft::FftPlanner;
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+/// # Fourier matrix
+/// Unitary `U` with `U[(j, k)] = exp(2πi jk / n) / sqrt(n)`, applied by FFT without forming it.
+///
+/// Every circulant matrix is `C = U * diag(lambda) * U^H`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Hash, Serialize, Deserialize)]
+pub struct FourierMatrix {
+    dim: usize,
+}
+
+impl FourierMatrix {
+    pub fn new(dim: usize) -> Self {
+        Self { dim }
+    }
+
+    /// Dimension.
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    /// Dense `U`. The phases are reduced modulo `n` before `exp` to keep the accuracy for large `n`.
+    pub fn mat(&self) -> Matrix<c64> {
+        let n = self.dim;
+        let scale = 1.0 / (n as f64).sqrt();
+
+        Matrix::from_fn(n, n, |j, k| {
+            c64::new(0.0, 2.0 * PI * ((j * k) % n) as f64 / n as f64).exp() * scale
+        })
+    }
+
+    fn apply(&self, b: &Matrix<c64>, adjoint: bool) -> Result<Matrix<c64>, MatrixError> {
+        let n = self.dim;
+        if n != b.rows() {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        let mut c = b.clone();
+        if n == 0 {
+            return Ok(c);
+        }
+
+        let mut planner = FftPlanner::new();
+        let fft = if adjoint {
+            planner.plan_fft_forward(n)
+        } else {
+            planner.plan_fft_inverse(n)
+        };
+        let scale = 1.0 / (n as f64).sqrt();
+
+        c.elems_mut().par_chunks_mut(n).for_each(|col| {
+            fft.process(col);
+            col.iter_mut().for_each(|e| *e *= scale);
+        });
+
+        Ok(c)
+    }
+
+    /// `U * B`
+    pub fn mat_mul(&self, b: &Matrix<c64>) -> Result<Matrix<c64>, MatrixError> {
+        self.apply(b, false)
+    }
+
+    /// `U^H * B`, which is also `U^-1 * B`.
+    pub fn adjoint_mat_mul(&self, b: &Matrix<c64>) -> Result<Matrix<c64>, MatrixError> {
+        self.apply(b, true)
+    }
+
+    /// `U * v`
+    pub fn vec_mul(&self, v: Vec<c64>) -> Result<Vec<c64>, MatrixError> {
+        let n = v.len();
+
+        Ok(self.mat_mul(&Matrix::unvec(v, n, 1)?)?.vec())
+    }
+
+    /// `U^H * v`, which is also `U^-1 * v`.
+    pub fn adjoint_vec_mul(&self, v: Vec<c64>) -> Result<Vec<c64>, MatrixError> {
+        let n = v.len();
+
+        Ok(self.adjoint_mat_mul(&Matrix::unvec(v, n, 1)?)?.vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    #[test]
+    fn it_works() {
+        let u = FourierMatrix::new(5);
+        let v = (0..5)
+            .map(|k| c64::new(k as f64, 1.0 - k as f64))
+            .collect::<Vec<_>>();
+        let uv = u.vec_mul(v.clone()).unwrap();
+        let expected = u.mat().dot(&v.clone().col_mat()).vec();
+
+        for k in 0..5 {
+            assert!((uv[k] - expected[k]).norm() < 1e-12);
+        }
+
+        let w = u.adjoint_vec_mul(uv).unwrap();
+
+        for k in 0..5 {
+            assert!((w[k] - v[k]).norm() < 1e-12);
+        }
+    }
+}
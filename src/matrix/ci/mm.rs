@@ -0,0 +1,74 @@
+use crate::matrix::ci::CirculantMatrix;
+use crate::{matrix::MatrixError, number::c64, Matrix};
+use rayon::prelude::*;
+use rustfft::FftPlanner;
+
+/// `C * B` for the circulant matrix `C` with the eigenvalues `lambda`, by FFT.
+///
+/// `C = F^-1 * diag(lambda) * F` where `F` is the discrete Fourier transform, so each column costs `O(n log n)`.
+pub(crate) fn circulant_apply(lambda: &[c64], b: &Matrix<c64>) -> Matrix<c64> {
+    let n = lambda.len();
+    let mut c = b.clone();
+    if n == 0 {
+        return c;
+    }
+
+    let mut planner = FftPlanner::new();
+    let fft = planner.plan_fft_forward(n);
+    let ifft = planner.plan_fft_inverse(n);
+
+    c.elems_mut().par_chunks_mut(n).for_each(|col| {
+        fft.process(col);
+        col.iter_mut()
+            .zip(lambda.iter())
+            .for_each(|(x, &l)| *x = *x * l / n as f64);
+        ifft.process(col);
+    });
+
+    c
+}
+
+macro_rules! impl_circulant_mul {
+    {$t: ty, $to_c64: expr, $from_c64: expr} => {
+        impl CirculantMatrix<$t> {
+            /// `C * B` in `O(n log n)` per column, by FFT.
+            pub fn mat_mul(&self, b: &Matrix<$t>) -> Result<Matrix<$t>, MatrixError> {
+                if self.col_elems().len() != b.rows() {
+                    return Err(MatrixError::DimensionMismatch);
+                }
+
+                let b = Matrix::from_fn(b.rows(), b.cols(), |i, j| $to_c64(b[(i, j)]));
+                let cb = circulant_apply(&self.eigenvalues(), &b);
+
+                Ok(Matrix::from_fn(cb.rows(), cb.cols(), |i, j| $from_c64(cb[(i, j)])))
+            }
+
+            /// `C * v` in `O(n log n)`, by FFT.
+            pub fn vec_mul(&self, v: Vec<$t>) -> Result<Vec<$t>, MatrixError> {
+                let n = v.len();
+
+                Ok(self.mat_mul(&Matrix::unvec(v, n, 1)?)?.vec())
+            }
+        }
+    };
+}
+
+impl_circulant_mul! {f64, |e: f64| c64::new(e, 0.0), |e: c64| e.re}
+impl_circulant_mul! {c64, |e: c64| e, |e: c64| e}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    #[test]
+    fn it_works() {
+        let a = CirculantMatrix::new(vec![1.0, 2.0, -3.0, 0.5]);
+        let dense = a.mat();
+        let b = Matrix::from(4, (0..8).map(|k| k as f64).collect()).unwrap();
+        let ab = a.mat_mul(&b).unwrap();
+        let expected = dense.dot(&b);
+
+        for k in 0..8 {
+            assert!((ab.elems()[k] - expected.elems()[k]).abs() < 1e-12);
+        }
+    }
+}
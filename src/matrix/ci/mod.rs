@@ -1,8 +1,13 @@
 pub mod evd;
+mod fourier;
+mod mm;
+mod sv;
+
+pub use fourier::FourierMatrix;
 
 use serde::{Deserialize, Serialize};
 
-use crate::number::Number;
+use crate::{number::Number, Matrix};
 
 #[derive(Clone, Debug, Default, PartialEq, Hash, Serialize, Deserialize)]
 pub struct CirculantMatrix<T = f64>
@@ -52,4 +57,11 @@ where
             .map(|&e| e)
             .collect::<Vec<_>>()
     }
+
+    /// Dense matrix.
+    pub fn mat(&self) -> Matrix<T> {
+        let n = self.col_elems.len();
+
+        Matrix::from_fn(n, n, |i, j| self.col_elems[(n + i - j) % n])
+    }
 }
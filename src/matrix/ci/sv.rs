@@ -0,0 +1,95 @@
+use crate::matrix::ci::{mm::circulant_apply, CirculantMatrix};
+use crate::{matrix::MatrixError, number::c64, Matrix};
+
+fn inv_eigenvalues(lambda: Vec<c64>) -> Result<Vec<c64>, MatrixError> {
+    lambda
+        .into_iter()
+        .map(|l| {
+            if l.norm() == 0.0 {
+                Err(MatrixError::Others("circulant matrix is singular".into()))
+            } else {
+                Ok(1.0 / l)
+            }
+        })
+        .collect()
+}
+
+macro_rules! impl_circulant_sv {
+    {$t: ty, $to_c64: expr, $from_c64: expr} => {
+        impl CirculantMatrix<$t> {
+            /// # Solve equation
+            /// `C^-1 * B = F^-1 * diag(lambda)^-1 * F * B` in `O(n log n)` per column, by FFT.
+            pub fn solve(&self, b: Matrix<$t>) -> Result<Matrix<$t>, MatrixError> {
+                if self.col_elems().len() != b.rows() {
+                    return Err(MatrixError::DimensionMismatch);
+                }
+
+                let lambda_inv = inv_eigenvalues(self.eigenvalues())?;
+                let b = Matrix::from_fn(b.rows(), b.cols(), |i, j| $to_c64(b[(i, j)]));
+                let x = circulant_apply(&lambda_inv, &b);
+
+                Ok(Matrix::from_fn(x.rows(), x.cols(), |i, j| $from_c64(x[(i, j)])))
+            }
+
+            /// # Inverse
+            /// The inverse of a circulant matrix is circulant, with the eigenvalues `1 / lambda`.
+            pub fn inverse(&self) -> Result<CirculantMatrix<$t>, MatrixError> {
+                let n = self.col_elems().len();
+                let lambda_inv = inv_eigenvalues(self.eigenvalues())?;
+                let mut e_1 = Matrix::<c64>::new(n, 1);
+                if n != 0 {
+                    e_1[(0, 0)] = c64::new(1.0, 0.0);
+                }
+                let col = circulant_apply(&lambda_inv, &e_1);
+
+                Ok(CirculantMatrix::new(
+                    col.vec().into_iter().map($from_c64).collect(),
+                ))
+            }
+        }
+    };
+}
+
+impl_circulant_sv! {f64, |e: f64| c64::new(e, 0.0), |e: c64| e.re}
+impl_circulant_sv! {c64, |e: c64| e, |e: c64| e}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    #[test]
+    fn it_works() {
+        let a = CirculantMatrix::new(vec![4.0, 2.0, -1.0, 0.5, 0.25]);
+        let b = Matrix::from(5, (0..10).map(|k| (k as f64).sin()).collect()).unwrap();
+        let x = a.solve(b.clone()).unwrap();
+        let ax = a.mat().dot(&x);
+
+        for k in 0..10 {
+            assert!((ax.elems()[k] - b.elems()[k]).abs() < 1e-12);
+        }
+
+        let i = a.inverse().unwrap().mat().dot(&a.mat());
+
+        for r in 0..5 {
+            for c in 0..5 {
+                let expected = if r == c { 1.0 } else { 0.0 };
+                assert!((i[(r, c)] - expected).abs() < 1e-12);
+            }
+        }
+
+        let a = CirculantMatrix::new(vec![
+            c64::new(1.0, 1.0),
+            c64::new(0.0, -2.0),
+            c64::new(3.0, 0.5),
+        ]);
+        let v = vec![c64::new(1.0, 0.0), c64::new(0.0, 1.0), c64::new(-1.0, 2.0)];
+        let av = a.vec_mul(v.clone()).unwrap();
+        let x = a.solve(av.col_mat()).unwrap();
+
+        for k in 0..3 {
+            assert!((x[(k, 0)] - v[k]).norm() < 1e-12);
+        }
+        assert!(CirculantMatrix::new(vec![1.0, 1.0])
+            .solve(Matrix::new(2, 1))
+            .is_err());
+    }
+}
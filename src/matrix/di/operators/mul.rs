@@ -1,5 +1,5 @@
 use crate::number::{c64, Number};
-use crate::DiagonalMatrix;
+use crate::{DiagonalMatrix, Matrix};
 use rayon::prelude::*;
 use std::ops::Mul;
 
@@ -129,6 +129,67 @@ macro_rules! impl_mul_vec {
 impl_mul_vec! {f64, mul_vec}
 impl_mul_vec! {c64, mul_vec}
 
+fn mul_ge<T>(lhs: &DiagonalMatrix<T>, rhs: Matrix<T>) -> Matrix<T>
+where
+    T: Number,
+{
+    if lhs.dim() != rhs.rows() {
+        panic!("Dimension mismatch.")
+    }
+
+    rhs.scale_rows(lhs).unwrap()
+}
+
+fn ge_mul<T>(lhs: Matrix<T>, rhs: &DiagonalMatrix<T>) -> Matrix<T>
+where
+    T: Number,
+{
+    if lhs.cols() != rhs.dim() {
+        panic!("Dimension mismatch.")
+    }
+
+    lhs.scale_cols(rhs).unwrap()
+}
+
+macro_rules! impl_mul_ge {
+  {$t: ty} => {
+      impl Mul<Matrix<$t>> for DiagonalMatrix<$t> {
+          type Output = Matrix<$t>;
+
+          fn mul(self, rhs: Matrix<$t>) -> Self::Output {
+              mul_ge(&self, rhs)
+          }
+      }
+
+      impl Mul<Matrix<$t>> for &DiagonalMatrix<$t> {
+          type Output = Matrix<$t>;
+
+          fn mul(self, rhs: Matrix<$t>) -> Self::Output {
+              mul_ge(self, rhs)
+          }
+      }
+
+      impl Mul<DiagonalMatrix<$t>> for Matrix<$t> {
+          type Output = Matrix<$t>;
+
+          fn mul(self, rhs: DiagonalMatrix<$t>) -> Self::Output {
+              ge_mul(self, &rhs)
+          }
+      }
+
+      impl Mul<&DiagonalMatrix<$t>> for Matrix<$t> {
+          type Output = Matrix<$t>;
+
+          fn mul(self, rhs: &DiagonalMatrix<$t>) -> Self::Output {
+              ge_mul(self, rhs)
+          }
+      }
+  };
+}
+
+impl_mul_ge! {f64}
+impl_mul_ge! {c64}
+
 #[cfg(test)]
 mod tests {
     use crate::*;
@@ -143,4 +204,10 @@ mod tests {
         let a = DiagonalMatrix::new(vec![2.0, 3.0]) * vec![4.0, 5.0];
         assert_eq!(a[0], 8.0);
     }
+
+    #[test]
+    fn mul_ge() {
+        let a = mat!(1.0, 2.0; 3.0, 4.0) * DiagonalMatrix::new(vec![2.0, 3.0]);
+        assert_eq!(a, mat!(2.0, 6.0; 6.0, 12.0));
+    }
 }
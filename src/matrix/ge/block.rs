@@ -0,0 +1,251 @@
+use crate::matrix::ge::Matrix;
+use crate::matrix::MatrixError;
+use crate::number::Number;
+use rayon::prelude::*;
+
+impl<T> Matrix<T>
+where
+    T: Number,
+{
+    /// # Horizontal concatenation
+    /// All matrices must have the same number of rows.
+    pub fn hstack(mats: &[&Matrix<T>]) -> Result<Self, MatrixError> {
+        let rows = match mats.first() {
+            Some(m) => m.rows,
+            None => return Ok(Matrix::new(0, 0)),
+        };
+
+        if mats.iter().any(|m| m.rows != rows) {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        Ok(Self {
+            rows,
+            cols: mats.iter().map(|m| m.cols).sum(),
+            elems: mats.iter().flat_map(|m| m.elems.iter().copied()).collect(),
+        })
+    }
+
+    /// # Vertical concatenation
+    /// All matrices must have the same number of columns.
+    pub fn vstack(mats: &[&Matrix<T>]) -> Result<Self, MatrixError> {
+        let cols = match mats.first() {
+            Some(m) => m.cols,
+            None => return Ok(Matrix::new(0, 0)),
+        };
+
+        if mats.iter().any(|m| m.cols != cols) {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let rows = mats.iter().map(|m| m.rows).sum();
+        let elems = (0..cols)
+            .into_par_iter()
+            .flat_map(|j| {
+                mats.iter()
+                    .flat_map(|m| m[j].iter().copied())
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        Ok(Self { rows, cols, elems })
+    }
+
+    /// # Block matrix
+    /// `blocks[i]` is the `i`-th row of blocks.
+    /// ```
+    /// use opensrdk_linear_algebra::*;
+    ///
+    /// let a = mat!(1.0; 2.0);
+    /// let b = mat!(3.0, 4.0; 5.0, 6.0);
+    /// let c = mat!(7.0);
+    /// let d = mat!(8.0, 9.0);
+    ///
+    /// let m = Matrix::block(&[&[&a, &b], &[&c, &d]]).unwrap();
+    /// assert_eq!(m, mat!(
+    ///   1.0, 3.0, 4.0;
+    ///   2.0, 5.0, 6.0;
+    ///   7.0, 8.0, 9.0
+    /// ));
+    /// ```
+    pub fn block(blocks: &[&[&Matrix<T>]]) -> Result<Self, MatrixError> {
+        let rows = blocks
+            .iter()
+            .map(|&row| Self::hstack(row))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Self::vstack(&rows.iter().collect::<Vec<_>>())
+    }
+
+    /// Overwrites the block starting at `(start_i, start_j)` with `sub`.
+    pub fn set_sub_matrix(
+        &mut self,
+        start_i: usize,
+        start_j: usize,
+        sub: &Matrix<T>,
+    ) -> Result<(), MatrixError> {
+        let end_i = start_i
+            .checked_add(sub.rows)
+            .ok_or(MatrixError::DimensionMismatch)?;
+        let end_j = start_j
+            .checked_add(sub.cols)
+            .ok_or(MatrixError::DimensionMismatch)?;
+        if self.rows < end_i || self.cols < end_j {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        for j in 0..sub.cols {
+            self[start_j + j][start_i..start_i + sub.rows].copy_from_slice(&sub[j]);
+        }
+
+        Ok(())
+    }
+
+    pub fn swap_rows(&mut self, i: usize, k: usize) {
+        if i == k {
+            return;
+        }
+
+        let rows = self.rows;
+        self.elems
+            .par_chunks_mut(rows.max(1))
+            .for_each(|col| col.swap(i, k));
+    }
+
+    pub fn swap_cols(&mut self, j: usize, l: usize) {
+        if j == l {
+            return;
+        }
+
+        let rows = self.rows;
+        let (j, l) = (j.min(l), j.max(l));
+        let (left, right) = self.elems.split_at_mut(l * rows);
+        left[j * rows..(j + 1) * rows].swap_with_slice(&mut right[..rows]);
+    }
+
+    /// Matrix of the rows in the order of `indices`. Indices may be repeated.
+    pub fn select_rows(&self, indices: &[usize]) -> Self {
+        let rows = indices.len();
+        let elems = (0..rows * self.cols)
+            .into_par_iter()
+            .map(|k| self[(indices[k % rows], k / rows)])
+            .collect();
+
+        Self {
+            rows,
+            cols: self.cols,
+            elems,
+        }
+    }
+
+    /// Matrix of the columns in the order of `indices`. Indices may be repeated.
+    pub fn select_cols(&self, indices: &[usize]) -> Self {
+        Self {
+            rows: self.rows,
+            cols: indices.len(),
+            elems: indices
+                .iter()
+                .flat_map(|&j| self[j].iter().copied())
+                .collect(),
+        }
+    }
+
+    /// Matrix without the rows of `indices`.
+    pub fn delete_rows(&self, indices: &[usize]) -> Self {
+        let rest = (0..self.rows)
+            .filter(|i| !indices.contains(i))
+            .collect::<Vec<_>>();
+
+        self.select_rows(&rest)
+    }
+
+    /// Matrix without the columns of `indices`.
+    pub fn delete_cols(&self, indices: &[usize]) -> Self {
+        let rest = (0..self.cols)
+            .filter(|j| !indices.contains(j))
+            .collect::<Vec<_>>();
+
+        self.select_cols(&rest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    #[test]
+    fn it_works() {
+        let a = mat!(
+            1.0, 2.0;
+            3.0, 4.0
+        );
+        let b = mat!(
+            5.0;
+            6.0
+        );
+
+        assert_eq!(
+            Matrix::hstack(&[&a, &b]).unwrap(),
+            mat!(
+                1.0, 2.0, 5.0;
+                3.0, 4.0, 6.0
+            )
+        );
+        assert_eq!(
+            Matrix::vstack(&[&a, &b.t()]).unwrap(),
+            mat!(
+                1.0, 2.0;
+                3.0, 4.0;
+                5.0, 6.0
+            )
+        );
+        assert!(Matrix::vstack(&[&a, &b]).is_err());
+
+        let mut c = Matrix::new(3, 3);
+        c.set_sub_matrix(1, 1, &a).unwrap();
+
+        assert_eq!(
+            c,
+            mat!(
+                0.0, 0.0, 0.0;
+                0.0, 1.0, 2.0;
+                0.0, 3.0, 4.0
+            )
+        );
+        assert!(c.set_sub_matrix(2, 0, &a).is_err());
+        assert!(c.set_sub_matrix(usize::MAX, 0, &a).is_err());
+        assert!(c.set_sub_matrix(0, usize::MAX, &a).is_err());
+    }
+
+    #[test]
+    fn rows_and_cols() {
+        let mut a = mat!(
+            1.0, 2.0, 3.0;
+            4.0, 5.0, 6.0;
+            7.0, 8.0, 9.0
+        );
+
+        assert_eq!(
+            a.select_rows(&[2, 0, 2]),
+            mat!(
+                7.0, 8.0, 9.0;
+                1.0, 2.0, 3.0;
+                7.0, 8.0, 9.0
+            )
+        );
+        assert_eq!(a.select_cols(&[1]), mat!(2.0; 5.0; 8.0));
+        assert_eq!(a.delete_rows(&[1]), mat!(1.0, 2.0, 3.0; 7.0, 8.0, 9.0));
+        assert_eq!(a.delete_cols(&[0, 2]), mat!(2.0; 5.0; 8.0));
+
+        a.swap_rows(0, 2);
+        a.swap_cols(2, 0);
+
+        assert_eq!(
+            a,
+            mat!(
+                9.0, 8.0, 7.0;
+                6.0, 5.0, 4.0;
+                3.0, 2.0, 1.0
+            )
+        );
+    }
+}
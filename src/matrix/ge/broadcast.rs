@@ -0,0 +1,134 @@
+use crate::matrix::ge::Matrix;
+use crate::matrix::MatrixError;
+use crate::number::Number;
+use crate::DiagonalMatrix;
+use rayon::prelude::*;
+
+impl<T> Matrix<T>
+where
+    T: Number,
+{
+    fn broadcast_row<F>(mut self, row: &[T], f: F) -> Result<Self, MatrixError>
+    where
+        F: Fn(&mut T, T) + Send + Sync,
+    {
+        if row.len() != self.cols {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        self.elems
+            .par_chunks_mut(self.rows.max(1))
+            .zip(row.par_iter())
+            .for_each(|(col, &r)| col.iter_mut().for_each(|e| f(e, r)));
+
+        Ok(self)
+    }
+
+    fn broadcast_col<F>(mut self, col: &[T], f: F) -> Result<Self, MatrixError>
+    where
+        F: Fn(&mut T, T) + Send + Sync,
+    {
+        if col.len() != self.rows {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        self.elems
+            .par_chunks_mut(self.rows.max(1))
+            .for_each(|c| c.iter_mut().zip(col.iter()).for_each(|(e, &r)| f(e, r)));
+
+        Ok(self)
+    }
+
+    /// # Broadcasting
+    /// Adds `row` to every row. The length of `row` must be `cols`.
+    pub fn add_row(self, row: &[T]) -> Result<Self, MatrixError> {
+        self.broadcast_row(row, |e, r| *e += r)
+    }
+
+    /// Subtracts `row` from every row. The length of `row` must be `cols`.
+    pub fn sub_row(self, row: &[T]) -> Result<Self, MatrixError> {
+        self.broadcast_row(row, |e, r| *e -= r)
+    }
+
+    /// Multiplies every row by `row` element-wise. The length of `row` must be `cols`.
+    pub fn mul_row(self, row: &[T]) -> Result<Self, MatrixError> {
+        self.broadcast_row(row, |e, r| *e *= r)
+    }
+
+    /// Divides every row by `row` element-wise. The length of `row` must be `cols`.
+    pub fn div_row(self, row: &[T]) -> Result<Self, MatrixError> {
+        self.broadcast_row(row, |e, r| *e /= r)
+    }
+
+    /// # Broadcasting
+    /// Adds `col` to every column. The length of `col` must be `rows`.
+    pub fn add_col(self, col: &[T]) -> Result<Self, MatrixError> {
+        self.broadcast_col(col, |e, c| *e += c)
+    }
+
+    /// Subtracts `col` from every column. The length of `col` must be `rows`.
+    pub fn sub_col(self, col: &[T]) -> Result<Self, MatrixError> {
+        self.broadcast_col(col, |e, c| *e -= c)
+    }
+
+    /// Multiplies every column by `col` element-wise. The length of `col` must be `rows`.
+    pub fn mul_col(self, col: &[T]) -> Result<Self, MatrixError> {
+        self.broadcast_col(col, |e, c| *e *= c)
+    }
+
+    /// Divides every column by `col` element-wise. The length of `col` must be `rows`.
+    pub fn div_col(self, col: &[T]) -> Result<Self, MatrixError> {
+        self.broadcast_col(col, |e, c| *e /= c)
+    }
+
+    /// `D * A`, scaling the row `i` by `d_i`.
+    pub fn scale_rows(self, d: &DiagonalMatrix<T>) -> Result<Self, MatrixError> {
+        self.mul_col(d.d())
+    }
+
+    /// `A * D`, scaling the column `j` by `d_j`.
+    pub fn scale_cols(self, d: &DiagonalMatrix<T>) -> Result<Self, MatrixError> {
+        self.mul_row(d.d())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    #[test]
+    fn it_works() {
+        let a = mat!(
+            1.0, 2.0, 3.0;
+            4.0, 5.0, 6.0
+        );
+
+        assert_eq!(
+            a.clone().sub_row(&a.mean(Axis::Col)).unwrap(),
+            mat!(
+                -1.5, -1.5, -1.5;
+                1.5, 1.5, 1.5
+            )
+        );
+        assert_eq!(
+            a.clone().add_col(&[10.0, 20.0]).unwrap(),
+            mat!(
+                11.0, 12.0, 13.0;
+                24.0, 25.0, 26.0
+            )
+        );
+        assert_eq!(
+            a.clone()
+                .scale_cols(&DiagonalMatrix::new(vec![1.0, 0.0, 2.0]))
+                .unwrap(),
+            mat!(
+                1.0, 0.0, 6.0;
+                4.0, 0.0, 12.0
+            )
+        );
+        assert_eq!(
+            DiagonalMatrix::new(vec![2.0, 3.0]) * a.clone(),
+            a.clone().mul_col(&[2.0, 3.0]).unwrap()
+        );
+        assert!(a.div_row(&[1.0, 2.0]).is_err());
+    }
+}
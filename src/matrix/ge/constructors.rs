@@ -0,0 +1,186 @@
+use crate::{
+    matrix::{ge::Matrix, MatrixError},
+    number::Number,
+};
+use rayon::prelude::*;
+
+impl<T> Matrix<T>
+where
+    T: Number,
+{
+    /// # Identity matrix
+    pub fn identity(n: usize) -> Self {
+        Self::from_fn(n, n, |i, j| if i == j { T::one() } else { T::default() })
+    }
+
+    /// # Matrix from a function
+    /// `f(i, j)` gives the element of the row `i` and the column `j`.
+    /// ```
+    /// use opensrdk_linear_algebra::*;
+    ///
+    /// let a = Matrix::from_fn(2, 3, |i, j| (i * 3 + j) as f64);
+    /// assert_eq!(a, mat!(
+    ///   0.0, 1.0, 2.0;
+    ///   3.0, 4.0, 5.0
+    /// ));
+    /// ```
+    pub fn from_fn<F>(rows: usize, cols: usize, f: F) -> Self
+    where
+        F: Fn(usize, usize) -> T + Send + Sync,
+    {
+        let mut mat = Matrix::new(rows, cols);
+
+        mat.elems
+            .par_iter_mut()
+            .enumerate()
+            .map(|(k, elem)| ((k % rows, k / rows), elem))
+            .for_each(|((i, j), elem)| *elem = f(i, j));
+
+        mat
+    }
+
+    /// `elems` are ordered row by row, unlike `Matrix::from`.
+    pub fn from_row_major(rows: usize, elems: Vec<T>) -> Result<Self, MatrixError> {
+        if rows == 0 {
+            return match elems.len() {
+                0 => Ok(Matrix::new(0, 0)),
+                _ => Err(MatrixError::DimensionMismatch),
+            };
+        }
+
+        let cols = elems.len() / rows;
+
+        if elems.len() != rows * cols {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        Ok(Self::from_fn(rows, cols, |i, j| elems[i * cols + j]))
+    }
+
+    /// All columns must have the same length.
+    pub fn from_columns(columns: &[Vec<T>]) -> Result<Self, MatrixError> {
+        let rows = columns.first().map(|c| c.len()).unwrap_or(0);
+
+        if columns.iter().any(|c| c.len() != rows) {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        Ok(Self::from_fn(rows, columns.len(), |i, j| columns[j][i]))
+    }
+
+    /// All rows must have the same length.
+    pub fn from_rows(rows: &[Vec<T>]) -> Result<Self, MatrixError> {
+        let cols = rows.first().map(|r| r.len()).unwrap_or(0);
+
+        if rows.iter().any(|r| r.len() != cols) {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        Ok(Self::from_fn(rows.len(), cols, |i, j| rows[i][j]))
+    }
+
+    /// # Diagonal matrix
+    /// as a dense matrix. Use `DiagonalMatrix` to keep only the diagonal elements.
+    pub fn from_diag(d: &[T]) -> Self {
+        let n = d.len();
+
+        Self::from_fn(n, n, |i, j| if i == j { d[i] } else { T::default() })
+    }
+
+    /// # Toeplitz matrix
+    /// as a dense matrix. Arguments follow `ToeplitzMatrix::from`.
+    /// - `col_elems`: First column elements. The length must be `dimension`.
+    /// - `row_elems`: First row elements without first element. The length must be `dimension - 1`.
+    pub fn toeplitz(col_elems: &[T], row_elems: &[T]) -> Result<Self, MatrixError> {
+        let n = col_elems.len();
+
+        if row_elems.len() != n.max(1) - 1 {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        Ok(Self::from_fn(n, n, |i, j| {
+            if i >= j {
+                col_elems[i - j]
+            } else {
+                row_elems[j - i - 1]
+            }
+        }))
+    }
+}
+
+impl Matrix {
+    /// # Hilbert matrix
+    /// `a_ij = 1 / (i + j + 1)`
+    pub fn hilbert(n: usize) -> Self {
+        Self::from_fn(n, n, |i, j| 1.0 / (i + j + 1) as f64)
+    }
+
+    /// # Vandermonde matrix
+    /// `a_ij = x_i^j` with `cols` columns.
+    pub fn vandermonde(x: &[f64], cols: usize) -> Self {
+        Self::from_fn(x.len(), cols, |i, j| x[i].powi(j as i32))
+    }
+
+    /// # Pascal matrix
+    /// Symmetric positive definite matrix with `a_ij = (i + j)! / (i! j!)`.
+    pub fn pascal(n: usize) -> Self {
+        Self::from_fn(n, n, |i, j| {
+            (1..=i.min(j))
+                .fold(1.0, |acc, k| acc * (i.max(j) + k) as f64 / k as f64)
+                .round()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    #[test]
+    fn it_works() {
+        let a = Matrix::<f64>::from_row_major(2, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        let b = Matrix::from_rows(&[vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+        let c = Matrix::from_columns(&[vec![1.0, 4.0], vec![2.0, 5.0], vec![3.0, 6.0]]).unwrap();
+
+        assert_eq!(
+            a,
+            mat!(
+                1.0, 2.0, 3.0;
+                4.0, 5.0, 6.0
+            )
+        );
+        assert_eq!(a, b);
+        assert_eq!(a, c);
+        assert!(Matrix::from_rows(&[vec![1.0], vec![2.0, 3.0]]).is_err());
+
+        assert_eq!(Matrix::<f64>::identity(2), Matrix::from_diag(&[1.0, 1.0]));
+        assert_eq!(
+            Matrix::toeplitz(&[1.0, 2.0, 3.0], &[4.0, 5.0]).unwrap(),
+            mat!(
+                1.0, 4.0, 5.0;
+                2.0, 1.0, 4.0;
+                3.0, 2.0, 1.0
+            )
+        );
+    }
+
+    #[test]
+    fn test_matrices() {
+        assert_eq!(Matrix::hilbert(2), mat!(1.0, 0.5; 0.5, 1.0 / 3.0));
+        assert_eq!(
+            Matrix::vandermonde(&[2.0, 3.0], 3),
+            mat!(
+                1.0, 2.0, 4.0;
+                1.0, 3.0, 9.0
+            )
+        );
+        assert_eq!(
+            Matrix::pascal(4),
+            mat!(
+                1.0, 1.0, 1.0, 1.0;
+                1.0, 2.0, 3.0, 4.0;
+                1.0, 3.0, 6.0, 10.0;
+                1.0, 4.0, 10.0, 20.0
+            )
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use crate::matrix::ge::Matrix;
+use crate::number::Number;
+use rayon::prelude::*;
+use std::iter::StepBy;
+use std::slice::{Chunks, ChunksMut, Iter};
+
+impl<T> Matrix<T>
+where
+    T: Number,
+{
+    /// # Column iterator
+    /// Each column is a contiguous slice.
+    pub fn iter_cols(&self) -> Chunks<'_, T> {
+        self.elems.chunks(self.rows.max(1))
+    }
+
+    pub fn iter_cols_mut(&mut self) -> ChunksMut<'_, T> {
+        self.elems.chunks_mut(self.rows.max(1))
+    }
+
+    /// # Row iterator
+    /// Each row is an iterator over the elements strided by `rows`.
+    pub fn iter_rows(&self) -> impl Iterator<Item = StepBy<Iter<'_, T>>> + '_ {
+        let rows = self.rows;
+
+        (0..rows).map(move |i| self.elems[i..].iter().step_by(rows))
+    }
+
+    /// Each row is collected as a `Vec` of mutable references, because rows are not contiguous.
+    pub fn iter_rows_mut(&mut self) -> std::vec::IntoIter<Vec<&mut T>> {
+        let rows = self.rows;
+        let mut iter_rows = (0..rows)
+            .map(|_| Vec::with_capacity(self.cols))
+            .collect::<Vec<_>>();
+
+        self.elems
+            .iter_mut()
+            .enumerate()
+            .for_each(|(k, e)| iter_rows[k % rows].push(e));
+
+        iter_rows.into_iter()
+    }
+
+    pub fn par_iter_cols(&self) -> rayon::slice::Chunks<'_, T> {
+        self.elems.par_chunks(self.rows.max(1))
+    }
+
+    pub fn par_iter_cols_mut(&mut self) -> rayon::slice::ChunksMut<'_, T> {
+        self.elems.par_chunks_mut(self.rows.max(1))
+    }
+
+    pub fn par_iter_rows(&self) -> impl IndexedParallelIterator<Item = StepBy<Iter<'_, T>>> + '_ {
+        let rows = self.rows;
+
+        (0..rows)
+            .into_par_iter()
+            .map(move |i| self.elems[i..].iter().step_by(rows))
+    }
+
+    pub fn par_iter_rows_mut(&mut self) -> rayon::vec::IntoIter<Vec<&mut T>> {
+        self.iter_rows_mut().collect::<Vec<_>>().into_par_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use rayon::prelude::*;
+    #[test]
+    fn it_works() {
+        let mut a = mat!(
+            1.0, 2.0, 3.0;
+            4.0, 5.0, 6.0
+        );
+
+        assert_eq!(
+            a.iter_cols().collect::<Vec<_>>(),
+            vec![[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]
+        );
+        assert_eq!(
+            a.iter_rows().map(|r| r.sum::<f64>()).collect::<Vec<_>>(),
+            vec![6.0, 15.0]
+        );
+        assert_eq!(
+            a.par_iter_rows()
+                .map(|r| r.copied().collect::<Vec<_>>())
+                .collect::<Vec<_>>(),
+            vec![a.eject_row(0), a.eject_row(1)]
+        );
+
+        a.iter_rows_mut()
+            .enumerate()
+            .for_each(|(i, r)| r.into_iter().for_each(|e| *e += i as f64));
+        a.par_iter_cols_mut().for_each(|c| c[0] *= 2.0);
+
+        assert_eq!(
+            a,
+            mat!(
+                2.0, 4.0, 6.0;
+                5.0, 6.0, 7.0
+            )
+        );
+    }
+}
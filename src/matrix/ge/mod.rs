@@ -1,13 +1,22 @@
+pub mod block;
+pub mod broadcast;
+pub mod constructors;
+pub mod iter;
 pub mod mm;
 pub mod operations;
 pub mod operators;
 pub mod or_un;
+pub mod qrf;
+pub(crate) mod random;
+pub mod rsvd;
+pub mod stats;
 pub mod svd;
 pub mod sy_he;
 pub mod tr;
 pub mod trf;
 pub mod tri;
 pub mod trs;
+pub mod view;
 
 use crate::{
     number::{c64, Number},
@@ -84,10 +93,23 @@ where
         self.cols
     }
 
+    /// `vec(A)`, stacking the columns.
     pub fn vec(self) -> Vec<T> {
         self.elems
     }
 
+    /// Inverse of `vec`, reshaping `v` into `rows x cols` column by column.
+    ///
+    /// `vec(A * X * B) = (B^T ⊗ A) * vec(X)`
+    pub fn unvec(v: Vec<T>, rows: usize, cols: usize) -> Result<Self, MatrixError> {
+        let mat = Self::from(rows, v)?;
+        if mat.cols != cols {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        Ok(mat)
+    }
+
     pub fn elems(&self) -> &[T] {
         &self.elems
     }
@@ -0,0 +1,68 @@
+use crate::{number::Number, Matrix, MatrixError};
+
+impl<T> Matrix<T>
+where
+    T: Number,
+{
+    /// # Khatri-Rao product
+    /// Column-wise Kronecker product. The column `j` is `a_j ⊗ b_j`.
+    ///
+    /// `A` is `m x n` and `B` is `p x n`, then the result is `mp x n`.
+    pub fn khatri_rao(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        if self.cols != rhs.cols {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let p = rhs.rows;
+
+        Ok(Matrix::from_fn(self.rows * p, self.cols, |i, j| {
+            self[(i / p, j)] * rhs[(i % p, j)]
+        }))
+    }
+
+    /// # Face-splitting product
+    /// Row-wise Kronecker product. The row `i` is `a_i ⊗ b_i`.
+    ///
+    /// `A` is `m x n` and `B` is `m x q`, then the result is `m x nq`.
+    pub fn face_splitting(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        if self.rows != rhs.rows {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let q = rhs.cols;
+
+        Ok(Matrix::from_fn(self.rows, self.cols * q, |i, j| {
+            self[(i, j / q)] * rhs[(i, j % q)]
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    #[test]
+    fn it_works() {
+        let a = mat![
+            1.0, 2.0;
+            3.0, 4.0
+        ];
+        let b = mat![
+            1.0, -1.0;
+            0.0, 2.0;
+            5.0, 3.0
+        ];
+        let c = a.khatri_rao(&b).unwrap();
+
+        assert_eq!(c.rows(), 6);
+        assert_eq!(c[(4, 1)], 4.0 * 2.0);
+        assert_eq!(c.t(), a.t().face_splitting(&b.t()).unwrap());
+
+        let kr = KroneckerMatrices::new(vec![a.clone(), b.clone()]).prod();
+        assert_eq!(c.select_cols(&[0]), kr.select_cols(&[0]));
+        assert_eq!(c.select_cols(&[1]), kr.select_cols(&[3]));
+
+        let x = Matrix::unvec(c.clone().vec(), 6, 2).unwrap();
+        assert_eq!(x, c);
+        assert!(Matrix::unvec(vec![1.0; 5], 2, 3).is_err());
+    }
+}
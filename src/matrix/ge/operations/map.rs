@@ -0,0 +1,137 @@
+use crate::matrix::ge::Matrix;
+use crate::matrix::MatrixError;
+use crate::number::Number;
+use rayon::prelude::*;
+
+impl<T> Matrix<T>
+where
+    T: Number,
+{
+    /// # Element-wise map
+    pub fn map<U, F>(&self, f: F) -> Matrix<U>
+    where
+        U: Number,
+        F: FnMut(T) -> U,
+    {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            elems: self.elems.iter().copied().map(f).collect(),
+        }
+    }
+
+    pub fn map_inplace<F>(&mut self, mut f: F)
+    where
+        F: FnMut(T) -> T,
+    {
+        self.elems.iter_mut().for_each(|e| *e = f(*e));
+    }
+
+    /// # Element-wise map of two matrices
+    /// `f(self[(i, j)], other[(i, j)])`
+    pub fn zip_map<U, V, F>(&self, other: &Matrix<U>, mut f: F) -> Result<Matrix<V>, MatrixError>
+    where
+        U: Number,
+        V: Number,
+        F: FnMut(T, U) -> V,
+    {
+        if self.rows != other.rows || self.cols != other.cols {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        Ok(Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            elems: self
+                .elems
+                .iter()
+                .zip(other.elems.iter())
+                .map(|(&a, &b)| f(a, b))
+                .collect(),
+        })
+    }
+
+    pub fn par_map<U, F>(&self, f: F) -> Matrix<U>
+    where
+        U: Number,
+        F: Fn(T) -> U + Send + Sync,
+    {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            elems: self.elems.par_iter().map(|&e| f(e)).collect(),
+        }
+    }
+
+    pub fn par_map_inplace<F>(&mut self, f: F)
+    where
+        F: Fn(T) -> T + Send + Sync,
+    {
+        self.elems.par_iter_mut().for_each(|e| *e = f(*e));
+    }
+
+    pub fn par_zip_map<U, V, F>(&self, other: &Matrix<U>, f: F) -> Result<Matrix<V>, MatrixError>
+    where
+        U: Number,
+        V: Number,
+        F: Fn(T, U) -> V + Send + Sync,
+    {
+        if self.rows != other.rows || self.cols != other.cols {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        Ok(Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            elems: self
+                .elems
+                .par_iter()
+                .zip(other.elems.par_iter())
+                .map(|(&a, &b)| f(a, b))
+                .collect(),
+        })
+    }
+
+    /// # Hadamard product
+    /// Element-wise product. Unlike `*`, returns an error instead of panicking if the sizes differ.
+    pub fn hadamard(&self, other: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.par_zip_map(other, |a, b| a * b)
+    }
+
+    /// # Hadamard division
+    /// Element-wise division.
+    pub fn hadamard_div(&self, other: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+    where
+        T: std::ops::Div<Output = T>,
+    {
+        self.par_zip_map(other, |a, b| a / b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    #[test]
+    fn it_works() {
+        let mut a = mat!(
+            1.0, 2.0;
+            3.0, 4.0
+        );
+        let b = a.map(|e| e * 2.0);
+
+        assert_eq!(b, a.par_map(|e| e * 2.0));
+        assert_eq!(
+            a.hadamard(&b).unwrap(),
+            a.zip_map(&b, |x, y| x * y).unwrap()
+        );
+        assert_eq!(b.hadamard_div(&a).unwrap(), mat!(2.0, 2.0; 2.0, 2.0));
+        assert!(a.hadamard(&Matrix::new(2, 3)).is_err());
+
+        let c: Matrix<c64> = a.map(|e| c64::new(0.0, e));
+        assert_eq!(c[(1, 0)], c64::new(0.0, 3.0));
+
+        a.map_inplace(|e| e + 1.0);
+        a.par_map_inplace(|e| e * 10.0);
+        assert_eq!(a, mat!(20.0, 30.0; 40.0, 50.0));
+    }
+}
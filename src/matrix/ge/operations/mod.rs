@@ -1,4 +1,6 @@
 pub mod adjoint;
 pub mod dot;
+pub mod khatri_rao;
+pub mod map;
 pub mod t;
 pub mod tr;
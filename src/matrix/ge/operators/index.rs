@@ -1,6 +1,8 @@
 use crate::matrix::ge::Matrix;
 use crate::number::Number;
-use std::ops::{Index, IndexMut};
+use std::ops::{
+    Index, IndexMut, Range, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive,
+};
 
 impl<T> Index<usize> for Matrix<T>
 where
@@ -42,6 +44,42 @@ where
     }
 }
 
+// Part of a column, `a[(1..3, j)]`. Rows are not contiguous, so use `Matrix::row` for them.
+
+macro_rules! impl_index_col_range {
+    {$($r: ty),*} => {
+        $(
+            impl<T> Index<($r, usize)> for Matrix<T>
+            where
+                T: Number,
+            {
+                type Output = [T];
+                fn index(&self, index: ($r, usize)) -> &Self::Output {
+                    &self[index.1][index.0]
+                }
+            }
+
+            impl<T> IndexMut<($r, usize)> for Matrix<T>
+            where
+                T: Number,
+            {
+                fn index_mut(&mut self, index: ($r, usize)) -> &mut Self::Output {
+                    &mut self[index.1][index.0]
+                }
+            }
+        )*
+    };
+}
+
+impl_index_col_range! {
+    Range<usize>,
+    RangeFrom<usize>,
+    RangeTo<usize>,
+    RangeFull,
+    RangeInclusive<usize>,
+    RangeToInclusive<usize>
+}
+
 mod tests {
     #[allow(unused_imports)]
     use crate::*;
@@ -66,4 +104,18 @@ mod tests {
         assert_eq!(a[(1, 0)], 3.0);
         assert_eq!(a[(1, 1)], 4.0);
     }
+
+    #[test]
+    fn col_range() {
+        let mut a = mat!(
+            1.0, 2.0;
+            3.0, 4.0;
+            5.0, 6.0
+        );
+        assert_eq!(a[(.., 1)], [2.0, 4.0, 6.0]);
+        assert_eq!(a[(1.., 0)], [3.0, 5.0]);
+
+        a[(..2, 0)].copy_from_slice(&[0.0, 0.0]);
+        assert_eq!(a[0], [0.0, 0.0, 5.0]);
+    }
 }
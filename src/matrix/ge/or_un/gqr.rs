@@ -0,0 +1,88 @@
+use crate::{ge::qrf::GEQRF, number::c64, Matrix, MatrixError};
+use lapack::{dorgqr, zungqr};
+
+impl GEQRF {
+    /// Generate an orthogonal matrix Q with `min(rows, cols)` columns by using the result of geqrf
+    pub fn orgqr(self) -> Result<Matrix, MatrixError> {
+        let GEQRF(mat, tau) = self;
+        let m = mat.rows;
+        let k = tau.len();
+
+        let mut q = if k == mat.cols {
+            mat
+        } else {
+            let mut q = Matrix::new(m, k);
+            q.elems.copy_from_slice(&mat.elems[..m * k]);
+            q
+        };
+
+        let lwork = k.max(1);
+        let mut work = vec![0.0; lwork];
+        let mut info = 0;
+
+        unsafe {
+            dorgqr(
+                m as i32,
+                k as i32,
+                k as i32,
+                &mut q.elems,
+                m.max(1) as i32,
+                &tau,
+                &mut work,
+                lwork as i32,
+                &mut info,
+            );
+        }
+
+        match info {
+            0 => Ok(q),
+            _ => Err(MatrixError::LapackRoutineError {
+                routine: "dorgqr".to_owned(),
+                info,
+            }),
+        }
+    }
+}
+
+impl GEQRF<c64> {
+    /// Generate a unitary matrix Q with `min(rows, cols)` columns by using the result of geqrf
+    pub fn ungqr(self) -> Result<Matrix<c64>, MatrixError> {
+        let GEQRF::<c64>(mat, tau) = self;
+        let m = mat.rows;
+        let k = tau.len();
+
+        let mut q = if k == mat.cols {
+            mat
+        } else {
+            let mut q = Matrix::new(m, k);
+            q.elems.copy_from_slice(&mat.elems[..m * k]);
+            q
+        };
+
+        let lwork = k.max(1);
+        let mut work = vec![c64::default(); lwork];
+        let mut info = 0;
+
+        unsafe {
+            zungqr(
+                m as i32,
+                k as i32,
+                k as i32,
+                &mut q.elems,
+                m.max(1) as i32,
+                &tau,
+                &mut work,
+                lwork as i32,
+                &mut info,
+            );
+        }
+
+        match info {
+            0 => Ok(q),
+            _ => Err(MatrixError::LapackRoutineError {
+                routine: "zungqr".to_owned(),
+                info,
+            }),
+        }
+    }
+}
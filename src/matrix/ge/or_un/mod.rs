@@ -1 +1,2 @@
+pub mod gqr;
 pub mod gtr;
@@ -0,0 +1,132 @@
+use crate::matrix::ge::Matrix;
+use crate::matrix::MatrixError;
+use crate::number::c64;
+use crate::Number;
+use lapack::{dgeqrf, zgeqrf};
+use serde::{Deserialize, Serialize};
+
+/// Householder vectors below the diagonal, `R` on and above the diagonal, and the scalar factors `tau`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct GEQRF<T = f64>(pub Matrix<T>, pub Vec<T>)
+where
+    T: Number;
+
+impl Matrix {
+    /// # QR decomposition
+    /// for f64
+    ///
+    /// `A = Q * R`
+    pub fn geqrf(self) -> Result<GEQRF, MatrixError> {
+        let m = self.rows;
+        let n = self.cols;
+        let mut tau = vec![0.0; m.min(n)];
+        let lwork = n.max(1);
+        let mut work = vec![0.0; lwork];
+        let mut info = 0;
+
+        let mut slf = self;
+
+        unsafe {
+            dgeqrf(
+                m as i32,
+                n as i32,
+                &mut slf.elems,
+                m.max(1) as i32,
+                &mut tau,
+                &mut work,
+                lwork as i32,
+                &mut info,
+            );
+        }
+
+        match info {
+            0 => Ok(GEQRF(slf, tau)),
+            _ => Err(MatrixError::LapackRoutineError {
+                routine: "dgeqrf".to_owned(),
+                info,
+            }),
+        }
+    }
+}
+
+impl Matrix<c64> {
+    /// # QR decomposition
+    /// for c64
+    ///
+    /// `A = Q * R`
+    pub fn geqrf(self) -> Result<GEQRF<c64>, MatrixError> {
+        let m = self.rows;
+        let n = self.cols;
+        let mut tau = vec![c64::default(); m.min(n)];
+        let lwork = n.max(1);
+        let mut work = vec![c64::default(); lwork];
+        let mut info = 0;
+
+        let mut slf = self;
+
+        unsafe {
+            zgeqrf(
+                m as i32,
+                n as i32,
+                &mut slf.elems,
+                m.max(1) as i32,
+                &mut tau,
+                &mut work,
+                lwork as i32,
+                &mut info,
+            );
+        }
+
+        match info {
+            0 => Ok(GEQRF::<c64>(slf, tau)),
+            _ => Err(MatrixError::LapackRoutineError {
+                routine: "zgeqrf".to_owned(),
+                info,
+            }),
+        }
+    }
+}
+
+impl<T> GEQRF<T>
+where
+    T: Number,
+{
+    /// Upper triangular `R` with `min(rows, cols)` rows.
+    pub fn r(&self) -> Matrix<T> {
+        let GEQRF(mat, _) = self;
+        let k = mat.rows.min(mat.cols);
+        let mut r = Matrix::new(k, mat.cols);
+
+        for j in 0..mat.cols {
+            for i in 0..k.min(j + 1) {
+                r[(i, j)] = mat[(i, j)];
+            }
+        }
+
+        r
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    #[test]
+    fn it_works() {
+        let a = mat!(
+            3.0, 1.0;
+            4.0, 2.0;
+            0.0, 5.0
+        );
+        let qr = a.clone().geqrf().unwrap();
+        let r = qr.r();
+        let q = qr.orgqr().unwrap();
+        let qr = q.dot(&r);
+
+        for i in 0..3 {
+            for j in 0..2 {
+                assert!((qr[(i, j)] - a[(i, j)]).abs() < 1e-12);
+            }
+        }
+        assert!((r[(0, 0)].abs() - 5.0).abs() < 1e-12);
+    }
+}
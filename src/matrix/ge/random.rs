@@ -0,0 +1,220 @@
+use crate::matrix::ge::sy_he::po::trf::POTRF;
+use crate::{number::c64, Matrix, MatrixError};
+use rand::prelude::*;
+use rayon::prelude::*;
+use std::f64::consts::{FRAC_1_SQRT_2, PI};
+
+/// Sample from the standard normal distribution by Box-Muller transform.
+pub(crate) fn standard_normal<R: Rng + ?Sized>(rng: &mut R) -> f64 {
+    let u1 = 1.0 - rng.gen::<f64>();
+    let u2 = rng.gen::<f64>();
+
+    (-2.0 * u1.ln()).sqrt() * (2.0 * PI * u2).cos()
+}
+
+impl Matrix {
+    /// # Random matrix
+    /// with elements drawn from the standard normal distribution.
+    ///
+    /// Pass a seeded generator such as `StdRng::seed_from_u64(seed)` to reproduce the result.
+    pub fn random_normal<R: Rng + ?Sized>(rows: usize, cols: usize, rng: &mut R) -> Self {
+        let mut mat = Matrix::new(rows, cols);
+        mat.elems.iter_mut().for_each(|e| *e = standard_normal(rng));
+
+        mat
+    }
+
+    /// # Random matrix
+    /// with elements drawn from the uniform distribution on `[low, high)`.
+    pub fn random_uniform<R: Rng + ?Sized>(
+        rows: usize,
+        cols: usize,
+        low: f64,
+        high: f64,
+        rng: &mut R,
+    ) -> Self {
+        let mut mat = Matrix::new(rows, cols);
+        mat.elems
+            .iter_mut()
+            .for_each(|e| *e = low + (high - low) * rng.gen::<f64>());
+
+        mat
+    }
+
+    /// # Random orthogonal matrix
+    /// distributed by the Haar measure.
+    pub fn random_orthogonal<R: Rng + ?Sized>(n: usize, rng: &mut R) -> Result<Self, MatrixError> {
+        let qr = Matrix::<f64>::random_normal(n, n, rng).geqrf()?;
+        let signs = (0..n).map(|i| qr.0[(i, i)].signum()).collect::<Vec<_>>();
+        let mut q = qr.orgqr()?;
+
+        q.elems
+            .par_iter_mut()
+            .enumerate()
+            .for_each(|(k, e)| *e *= signs[k / n]);
+
+        Ok(q)
+    }
+
+    /// # Random symmetric positive definite matrix
+    /// with the condition number `cond`.
+    /// Eigenvalues are spaced geometrically from `1` to `cond` and eigenvectors are Haar distributed.
+    pub fn random_spd<R: Rng + ?Sized>(
+        n: usize,
+        cond: f64,
+        rng: &mut R,
+    ) -> Result<Self, MatrixError> {
+        if !(1.0..).contains(&cond) {
+            return Err(MatrixError::Others("`cond` must be at least 1".into()));
+        }
+
+        let q = Matrix::random_orthogonal(n, rng)?;
+        let lambda = (0..n)
+            .map(|i| match n {
+                1 => 1.0,
+                _ => cond.powf(i as f64 / (n - 1) as f64),
+            })
+            .collect::<Vec<_>>();
+
+        let mut q_lambda = q.clone();
+        q_lambda
+            .elems
+            .par_iter_mut()
+            .enumerate()
+            .for_each(|(k, e)| *e *= lambda[k / n]);
+
+        let a = q_lambda.dot(&q.t());
+        let at = a.t();
+
+        Ok((a + at) * 0.5)
+    }
+
+    /// # Wishart sample
+    /// `W = X * X^T` where the `dof` columns of `X` are drawn from `N(0, scale)`.
+    pub fn random_wishart<R: Rng + ?Sized>(
+        scale: &Matrix,
+        dof: usize,
+        rng: &mut R,
+    ) -> Result<Self, MatrixError> {
+        let n = scale.rows;
+        if n != scale.cols {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let POTRF(mut l) = scale.clone().potrf()?;
+        for j in 0..n {
+            for i in 0..j {
+                l[(i, j)] = 0.0;
+            }
+        }
+
+        let x = l.dot(&Matrix::<f64>::random_normal(n, dof, rng));
+
+        Ok(x.dot(&x.t()))
+    }
+}
+
+impl Matrix<c64> {
+    /// # Random matrix
+    /// with elements drawn from the standard complex normal distribution, whose real and imaginary parts have the variance `1 / 2`.
+    pub fn random_normal<R: Rng + ?Sized>(rows: usize, cols: usize, rng: &mut R) -> Self {
+        let mut mat = Matrix::<c64>::new(rows, cols);
+        mat.elems.iter_mut().for_each(|e| {
+            *e = c64::new(
+                FRAC_1_SQRT_2 * standard_normal(rng),
+                FRAC_1_SQRT_2 * standard_normal(rng),
+            )
+        });
+
+        mat
+    }
+
+    /// # Random unitary matrix
+    /// distributed by the Haar measure.
+    pub fn random_unitary<R: Rng + ?Sized>(n: usize, rng: &mut R) -> Result<Self, MatrixError> {
+        let qr = Matrix::<c64>::random_normal(n, n, rng).geqrf()?;
+        let phases = (0..n)
+            .map(|i| {
+                let r = qr.0[(i, i)];
+                let norm = r.norm();
+                if norm == 0.0 {
+                    c64::new(1.0, 0.0)
+                } else {
+                    r / norm
+                }
+            })
+            .collect::<Vec<_>>();
+        let mut q = qr.ungqr()?;
+
+        q.elems
+            .par_iter_mut()
+            .enumerate()
+            .for_each(|(k, e)| *e *= phases[k / n]);
+
+        Ok(q)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use rand::prelude::*;
+
+    #[test]
+    fn it_works() {
+        let a = Matrix::<f64>::random_normal(3, 4, &mut StdRng::seed_from_u64(1));
+        let b = Matrix::<f64>::random_normal(3, 4, &mut StdRng::seed_from_u64(1));
+
+        assert_eq!(a, b);
+
+        let c = Matrix::random_uniform(3, 4, -1.0, 1.0, &mut StdRng::seed_from_u64(1));
+
+        assert!(c.elems().iter().all(|e| (-1.0..1.0).contains(e)));
+    }
+
+    #[test]
+    fn orthogonal() {
+        let mut rng = StdRng::seed_from_u64(2);
+        let q = Matrix::random_orthogonal(4, &mut rng).unwrap();
+        let i = q.t().dot(&q);
+
+        for r in 0..4 {
+            for c in 0..4 {
+                let expected = if r == c { 1.0 } else { 0.0 };
+                assert!((i[(r, c)] - expected).abs() < 1e-12);
+            }
+        }
+
+        let a = Matrix::random_spd(4, 100.0, &mut rng).unwrap();
+
+        assert_eq!(a, a.t());
+        assert!(a.clone().potrf().is_ok());
+        assert!((a.tr() - (0..4).map(|i| 100f64.powf(i as f64 / 3.0)).sum::<f64>()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn wishart() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let scale = Matrix::random_spd(3, 10.0, &mut rng).unwrap();
+        let w = Matrix::random_wishart(&scale, 5, &mut rng).unwrap();
+
+        for k in 0..9 {
+            assert!((w.elems()[k] - w.t().elems()[k]).abs() < 1e-12);
+        }
+        assert!(w.potrf().is_ok());
+        assert!(Matrix::random_wishart(&Matrix::new(2, 3), 5, &mut rng).is_err());
+    }
+
+    #[test]
+    fn unitary() {
+        let q = Matrix::random_unitary(4, &mut StdRng::seed_from_u64(4)).unwrap();
+
+        for r in 0..4 {
+            for c in 0..4 {
+                let qhq = (0..4).map(|k| q[(k, r)].conj() * q[(k, c)]).sum::<c64>();
+                let expected = if r == c { 1.0 } else { 0.0 };
+                assert!((qhq - c64::new(expected, 0.0)).norm() < 1e-12);
+            }
+        }
+    }
+}
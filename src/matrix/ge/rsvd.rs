@@ -0,0 +1,138 @@
+use crate::{Matrix, MatrixError};
+use rand::prelude::*;
+use std::error::Error;
+
+fn orthonormalize(mat: Matrix) -> Result<Matrix, MatrixError> {
+    mat.geqrf()?.orgqr()
+}
+
+impl Matrix {
+    /// # Randomized range finder
+    /// Orthonormal `Q` with `l` columns such that `A ≈ Q * Q^T * A`.
+    ///
+    /// `A` is `m x n` and given by `mat_mul(X) = A * X` and `t_mat_mul(Y) = A^T * Y`.
+    /// `power_iterations` passes through `A * A^T` sharpen the spectrum and are re-orthonormalized every time.
+    pub fn range_finder<R: Rng + ?Sized>(
+        n: usize,
+        l: usize,
+        mat_mul: &dyn Fn(Matrix) -> Result<Matrix, Box<dyn Error + Send + Sync>>,
+        t_mat_mul: &dyn Fn(Matrix) -> Result<Matrix, Box<dyn Error + Send + Sync>>,
+        power_iterations: usize,
+        rng: &mut R,
+    ) -> Result<Matrix, MatrixError> {
+        let omega = Matrix::<f64>::random_normal(n, l, rng);
+        let mut q = orthonormalize(mat_mul(omega)?)?;
+
+        for _ in 0..power_iterations {
+            let z = orthonormalize(t_mat_mul(q)?)?;
+            q = orthonormalize(mat_mul(z)?)?;
+        }
+
+        Ok(q)
+    }
+
+    /// # Randomized SVD
+    /// by Halko, Martinsson and Tropp.
+    ///
+    /// `A ≈ U * diag(sigma) * V^T` truncated to the rank `k`, where `A` is `m x n` and given by `mat_mul(X) = A * X` and `t_mat_mul(Y) = A^T * Y`.
+    /// `k + oversampling` random vectors are used to sample the range of `A`.
+    ///
+    /// `(u, sigma, vt)`
+    pub fn gesvd_randomized_k<R: Rng + ?Sized>(
+        n: usize,
+        k: usize,
+        mat_mul: &dyn Fn(Matrix) -> Result<Matrix, Box<dyn Error + Send + Sync>>,
+        t_mat_mul: &dyn Fn(Matrix) -> Result<Matrix, Box<dyn Error + Send + Sync>>,
+        oversampling: usize,
+        power_iterations: usize,
+        rng: &mut R,
+    ) -> Result<(Matrix, Vec<f64>, Matrix), MatrixError> {
+        let l = (k + oversampling).min(n);
+        let q = Matrix::range_finder(n, l, mat_mul, t_mat_mul, power_iterations, rng)?;
+        if k > q.cols {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        // B^T = A^T * Q = Q2 * R, so B = R^T * Q2^T.
+        let qr = t_mat_mul(q.clone())?.geqrf()?;
+        let rt = qr.r().t();
+        let q2 = qr.orgqr()?;
+
+        let (u_r, sigma, vt_r) = rt.gesvd()?;
+
+        let u = q.dot(&u_r).select_cols(&(0..k).collect::<Vec<_>>());
+        let sigma = sigma.elems[..k].to_vec();
+        let vt = vt_r.dot(&q2.t()).select_rows(&(0..k).collect::<Vec<_>>());
+
+        Ok((u, sigma, vt))
+    }
+
+    /// # Randomized SVD
+    /// Truncated SVD of the rank `k`. See `Matrix::gesvd_randomized_k`.
+    ///
+    /// `(u, sigma, vt)`
+    pub fn gesvd_randomized<R: Rng + ?Sized>(
+        &self,
+        k: usize,
+        oversampling: usize,
+        power_iterations: usize,
+        rng: &mut R,
+    ) -> Result<(Matrix, Vec<f64>, Matrix), MatrixError> {
+        if k > self.rows.min(self.cols) {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        Matrix::gesvd_randomized_k(
+            self.cols,
+            k,
+            &|x| Ok(self.dot(&x)),
+            &|y| Ok(self.t().dot(&y)),
+            oversampling,
+            power_iterations,
+            rng,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use rand::prelude::*;
+
+    #[test]
+    fn it_works() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let u = Matrix::random_orthogonal(8, &mut rng).unwrap();
+        let v = Matrix::random_orthogonal(6, &mut rng).unwrap();
+        let s = [10.0, 5.0, 2.0, 0.0, 0.0, 0.0];
+        let mut a = Matrix::new(8, 6);
+        for j in 0..6 {
+            for i in 0..8 {
+                a[(i, j)] = (0..6).map(|l| u[(i, l)] * s[l] * v[(j, l)]).sum();
+            }
+        }
+
+        let (u_k, sigma, vt_k) = a.gesvd_randomized(3, 2, 1, &mut rng).unwrap();
+
+        assert_eq!(u_k.rows(), 8);
+        assert_eq!(u_k.cols(), 3);
+        assert_eq!(vt_k.rows(), 3);
+        assert_eq!(vt_k.cols(), 6);
+        for (&e, &s) in sigma.iter().zip(s.iter()) {
+            assert!((e - s).abs() < 1e-9);
+        }
+
+        let mut us = u_k.clone();
+        for j in 0..3 {
+            for i in 0..8 {
+                us[(i, j)] *= sigma[j];
+            }
+        }
+        let b = us.dot(&vt_k);
+        for j in 0..6 {
+            for i in 0..8 {
+                assert!((a[(i, j)] - b[(i, j)]).abs() < 1e-9);
+            }
+        }
+    }
+}
@@ -0,0 +1,243 @@
+use crate::matrix::ge::Matrix;
+use crate::matrix::MatrixError;
+use crate::number::Number;
+use blas::{dger, dsyrk};
+use rayon::prelude::*;
+
+/// Direction of the axis reductions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Axis {
+    /// Reduces each row to one value. The result has `rows` elements.
+    Row,
+    /// Reduces each column to one value. The result has `cols` elements.
+    Col,
+}
+
+impl<T> Matrix<T>
+where
+    T: Number,
+{
+    /// Sum of each row. The result has `rows` elements.
+    pub fn sum_rows(&self) -> Vec<T> {
+        self.par_iter_rows().map(|r| r.copied().sum()).collect()
+    }
+
+    /// Sum of each column. The result has `cols` elements.
+    pub fn sum_cols(&self) -> Vec<T> {
+        (0..self.cols)
+            .into_par_iter()
+            .map(|j| self[j].iter().copied().sum())
+            .collect()
+    }
+
+    pub fn sum_axis(&self, axis: Axis) -> Vec<T> {
+        match axis {
+            Axis::Row => self.sum_rows(),
+            Axis::Col => self.sum_cols(),
+        }
+    }
+
+    fn reduce_axis<U, F>(&self, axis: Axis, f: F) -> Vec<U>
+    where
+        U: Send,
+        F: Fn(&mut dyn Iterator<Item = T>) -> U + Send + Sync,
+    {
+        match axis {
+            Axis::Row => self.par_iter_rows().map(|r| f(&mut r.copied())).collect(),
+            Axis::Col => (0..self.cols)
+                .into_par_iter()
+                .map(|j| f(&mut self[j].iter().copied()))
+                .collect(),
+        }
+    }
+}
+
+impl Matrix {
+    /// # Mean
+    /// along the `axis`.
+    pub fn mean(&self, axis: Axis) -> Vec<f64> {
+        let n = match axis {
+            Axis::Row => self.cols,
+            Axis::Col => self.rows,
+        } as f64;
+
+        self.sum_axis(axis).into_iter().map(|s| s / n).collect()
+    }
+
+    /// # Variance
+    /// along the `axis`. The sum of squared deviations is divided by `n - ddof`.
+    pub fn var(&self, axis: Axis, ddof: usize) -> Vec<f64> {
+        self.reduce_axis(axis, |iter| {
+            let v = iter.collect::<Vec<_>>();
+            let mean = v.iter().sum::<f64>() / v.len() as f64;
+
+            v.iter().map(|e| (e - mean).powi(2)).sum::<f64>() / (v.len() as f64 - ddof as f64)
+        })
+    }
+
+    /// # Standard deviation
+    /// along the `axis`. See `var` for `ddof`.
+    pub fn std(&self, axis: Axis, ddof: usize) -> Vec<f64> {
+        self.var(axis, ddof).into_iter().map(|v| v.sqrt()).collect()
+    }
+
+    /// Minimum along the `axis`. `NaN` is ignored.
+    pub fn min(&self, axis: Axis) -> Vec<f64> {
+        self.reduce_axis(axis, |iter| iter.fold(f64::INFINITY, f64::min))
+    }
+
+    /// Maximum along the `axis`. `NaN` is ignored.
+    pub fn max(&self, axis: Axis) -> Vec<f64> {
+        self.reduce_axis(axis, |iter| iter.fold(f64::NEG_INFINITY, f64::max))
+    }
+
+    /// Index of the minimum along the `axis`. The first one is returned for ties.
+    pub fn argmin(&self, axis: Axis) -> Vec<usize> {
+        self.reduce_axis(axis, |iter| {
+            iter.enumerate()
+                .fold(
+                    (0, f64::INFINITY),
+                    |acc, (k, e)| if e < acc.1 { (k, e) } else { acc },
+                )
+                .0
+        })
+    }
+
+    /// Index of the maximum along the `axis`. The first one is returned for ties.
+    pub fn argmax(&self, axis: Axis) -> Vec<usize> {
+        self.reduce_axis(axis, |iter| {
+            iter.enumerate()
+                .fold(
+                    (0, f64::NEG_INFINITY),
+                    |acc, (k, e)| if e > acc.1 { (k, e) } else { acc },
+                )
+                .0
+        })
+    }
+
+    /// # Centering
+    /// Subtracts the mean of each column from the column with a rank-1 update `X - 1 * mean^T`.
+    pub fn centered(&self) -> Matrix {
+        let m = self.rows;
+        let n = self.cols;
+        let mean = self.mean(Axis::Col);
+        let ones = vec![1.0; m];
+        let mut x = self.clone();
+
+        unsafe {
+            dger(
+                m as i32,
+                n as i32,
+                -1.0,
+                &ones,
+                1,
+                &mean,
+                1,
+                &mut x.elems,
+                m.max(1) as i32,
+            );
+        }
+
+        x
+    }
+
+    /// # Covariance matrix
+    /// of the columns, treating each row as an observation. Divided by `rows - 1`.
+    pub fn covariance(&self) -> Result<Matrix, MatrixError> {
+        let m = self.rows;
+        let n = self.cols;
+        if m < 2 {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let x = self.centered();
+        let mut cov = Matrix::new(n, n);
+
+        unsafe {
+            dsyrk(
+                b'L',
+                b'T',
+                n as i32,
+                m as i32,
+                1.0 / (m - 1) as f64,
+                &x.elems,
+                m as i32,
+                0.0,
+                &mut cov.elems,
+                n.max(1) as i32,
+            );
+        }
+
+        for j in 0..n {
+            for i in 0..j {
+                cov[(i, j)] = cov[(j, i)];
+            }
+        }
+
+        Ok(cov)
+    }
+
+    /// # Correlation matrix
+    /// of the columns, treating each row as an observation.
+    pub fn correlation(&self) -> Result<Matrix, MatrixError> {
+        let cov = self.covariance()?;
+        let n = cov.rows;
+        let s = (0..n).map(|i| cov[(i, i)].sqrt()).collect::<Vec<_>>();
+
+        Ok(Matrix::from_fn(n, n, |i, j| {
+            if i == j {
+                1.0
+            } else {
+                cov[(i, j)] / (s[i] * s[j])
+            }
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    #[test]
+    fn it_works() {
+        let a = mat!(
+            1.0, 2.0, 9.0;
+            3.0, 6.0, 5.0;
+            5.0, 4.0, 1.0
+        );
+
+        assert_eq!(a.sum_rows(), vec![12.0, 14.0, 10.0]);
+        assert_eq!(a.sum_cols(), vec![9.0, 12.0, 15.0]);
+        assert_eq!(a.mean(Axis::Col), vec![3.0, 4.0, 5.0]);
+        assert_eq!(a.var(Axis::Col, 1), vec![4.0, 4.0, 16.0]);
+        assert_eq!(a.std(Axis::Col, 0)[0], (8.0f64 / 3.0).sqrt());
+        assert_eq!(a.max(Axis::Row), vec![9.0, 6.0, 5.0]);
+        assert_eq!(a.min(Axis::Col), vec![1.0, 2.0, 1.0]);
+        assert_eq!(a.argmax(Axis::Row), vec![2, 1, 0]);
+        assert_eq!(a.argmin(Axis::Col), vec![0, 0, 2]);
+    }
+
+    #[test]
+    fn covariance() {
+        let a = mat!(
+            1.0, 2.0, 9.0;
+            3.0, 6.0, 5.0;
+            5.0, 4.0, 1.0
+        );
+        let cov = a.covariance().unwrap();
+
+        assert_eq!(
+            cov,
+            mat!(
+                4.0, 2.0, -8.0;
+                2.0, 4.0, -4.0;
+                -8.0, -4.0, 16.0
+            )
+        );
+
+        let corr = a.correlation().unwrap();
+
+        assert!((corr[(0, 1)] - 0.5).abs() < 1e-12);
+        assert!((corr[(0, 2)] + 1.0).abs() < 1e-12);
+        assert_eq!(corr[(2, 0)], corr[(0, 2)]);
+    }
+}
@@ -0,0 +1,64 @@
+use crate::matrix::ge::Matrix;
+use crate::matrix::MatrixError;
+use lapack::dsyev;
+
+impl Matrix {
+    /// # Eigen decomposition
+    /// for symmetric matrix
+    ///
+    /// `A = Q * diag(lambda) * Q^T` with eigenvalues in ascending order.
+    ///
+    /// `(lambda, q)`
+    pub fn syev(self) -> Result<(Vec<f64>, Matrix), MatrixError> {
+        let n = self.rows;
+        if n != self.cols {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let mut mat = self;
+        let mut w = vec![0.0; n];
+        let lwork = 1.max(3 * n);
+        let mut work = vec![0.0; lwork];
+        let mut info = 0;
+
+        unsafe {
+            dsyev(
+                b'V',
+                b'L',
+                n as i32,
+                &mut mat.elems,
+                n.max(1) as i32,
+                &mut w,
+                &mut work,
+                lwork as i32,
+                &mut info,
+            );
+        }
+
+        match info {
+            0 => Ok((w, mat)),
+            _ => Err(MatrixError::LapackRoutineError {
+                routine: "dsyev".to_owned(),
+                info,
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    #[test]
+    fn it_works() {
+        let a = mat!(
+            2.0, 1.0;
+            1.0, 2.0
+        );
+        let (lambda, q) = a.syev().unwrap();
+
+        assert!((lambda[0] - 1.0).abs() < 1e-12);
+        assert!((lambda[1] - 3.0).abs() < 1e-12);
+        assert!((q[(0, 1)].abs() - 0.5f64.sqrt()).abs() < 1e-12);
+        assert!((q[(0, 1)] - q[(1, 1)]).abs() < 1e-12);
+    }
+}
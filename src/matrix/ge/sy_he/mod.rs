@@ -1,5 +1,6 @@
 pub mod po;
 
+pub(crate) mod ev;
 pub mod trd;
 pub mod trf;
 pub mod tri;
@@ -0,0 +1,176 @@
+use super::trf::POTRF;
+use super::up::{rank1_update, CholeskyElement};
+use crate::matrix::ge::Matrix;
+use crate::matrix::MatrixError;
+use crate::number::c64;
+use blas::{dgemm, dtrsm, zgemm, ztrsm};
+
+impl<T> POTRF<T>
+where
+    T: CholeskyElement,
+{
+    /// `[[L, 0], [W^*, L22]]` from `W = L^-1 * B` and `L22`.
+    fn assemble(&mut self, w: &Matrix<T>, l22: &Matrix<T>) {
+        let n = self.0.rows;
+        let k = l22.rows;
+        let m = n + k;
+        let l = &self.0;
+
+        let elems = (0..m)
+            .flat_map(|j| (0..m).map(move |i| (i, j)))
+            .map(|(i, j)| match (i < n, j < n) {
+                (true, true) => l[(i, j)],
+                (false, true) => w[(j, i - n)].conj(),
+                (true, false) => T::default(),
+                (false, false) => l22[(i - n, j - n)],
+            })
+            .collect();
+
+        self.0 = Matrix {
+            rows: m,
+            cols: m,
+            elems,
+        };
+    }
+
+    /// # Remove rows and columns
+    /// Refactors `A` without the rows and columns of `indices` in O(n^2 k), by a rank-1 update of the trailing block for each index.
+    pub fn remove(&mut self, indices: &[usize]) -> Result<(), MatrixError> {
+        let mut indices = indices.to_vec();
+        indices.sort_unstable();
+        indices.dedup();
+
+        if indices.iter().any(|&i| self.0.rows <= i) {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        for &j in indices.iter().rev() {
+            let n = self.0.rows;
+            let v = self.0[j][j + 1..].to_vec();
+            let mut l = self.0.delete_rows(&[j]).delete_cols(&[j]);
+            let m = n - 1;
+
+            rank1_update(
+                &mut l.elems[j * m..],
+                m - j,
+                |a, b| j + a + b * m,
+                &v,
+                false,
+            )?;
+
+            self.0 = l;
+        }
+
+        Ok(())
+    }
+}
+
+macro_rules! impl_append {
+    {$t: ty, $trsm: ident, $gemm: ident, $trans: expr, $one: expr} => {
+        impl POTRF<$t> {
+            /// # Block append
+            /// Extends the factor of `A` to the factor of `[[A, B], [B^*, C]]` without refactoring the old part, in O(n^2 k).
+            /// - `cross_block`: `B` with `n` rows and `k` columns.
+            /// - `new_diag_block`: `C` with `k` rows and `k` columns.
+            pub fn append(
+                &mut self,
+                cross_block: &Matrix<$t>,
+                new_diag_block: Matrix<$t>,
+            ) -> Result<(), MatrixError> {
+                let n = self.0.rows;
+                let k = new_diag_block.rows;
+                if cross_block.rows != n || cross_block.cols != k || new_diag_block.cols != k {
+                    return Err(MatrixError::DimensionMismatch);
+                }
+
+                let mut w = cross_block.clone();
+                let mut s = new_diag_block;
+
+                if n != 0 && k != 0 {
+                    unsafe {
+                        $trsm(
+                            b'L',
+                            b'L',
+                            b'N',
+                            b'N',
+                            n as i32,
+                            k as i32,
+                            $one,
+                            &self.0.elems,
+                            n as i32,
+                            &mut w.elems,
+                            n as i32,
+                        );
+                        $gemm(
+                            $trans,
+                            b'N',
+                            k as i32,
+                            k as i32,
+                            n as i32,
+                            -$one,
+                            &w.elems,
+                            n as i32,
+                            &w.elems,
+                            n as i32,
+                            $one,
+                            &mut s.elems,
+                            k as i32,
+                        );
+                    }
+                }
+
+                let POTRF(l22) = s.potrf()?;
+                self.assemble(&w, &l22);
+
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_append! {f64, dtrsm, dgemm, b'T', 1.0}
+impl_append! {c64, ztrsm, zgemm, b'C', c64::new(1.0, 0.0)}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    #[test]
+    fn it_works() {
+        let a = mat!(
+            4.0, 2.0, 1.0, 0.5;
+            2.0, 5.0, 3.0, 1.0;
+            1.0, 3.0, 6.0, 2.0;
+            0.5, 1.0, 2.0, 7.0
+        );
+        let expected = a.clone().potrf().unwrap().l();
+
+        let mut l = a.eject_sub_matrix(0, 0, 2, 2).potrf().unwrap();
+        l.append(
+            &a.eject_sub_matrix(0, 2, 2, 2),
+            a.eject_sub_matrix(2, 2, 2, 2),
+        )
+        .unwrap();
+        let actual = l.l();
+
+        for i in 0..4 {
+            for j in 0..4 {
+                assert!((actual[(i, j)] - expected[(i, j)]).abs() < 1e-12);
+            }
+        }
+
+        l.remove(&[2, 0]).unwrap();
+        let expected = a
+            .select_rows(&[1, 3])
+            .select_cols(&[1, 3])
+            .potrf()
+            .unwrap()
+            .l();
+        let actual = l.l();
+
+        for i in 0..2 {
+            for j in 0..2 {
+                assert!((actual[(i, j)] - expected[(i, j)]).abs() < 1e-12);
+            }
+        }
+    }
+}
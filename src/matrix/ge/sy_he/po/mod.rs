@@ -1,4 +1,9 @@
+pub mod append;
+pub mod pstrf;
 pub mod sv;
 pub mod trf;
 pub mod tri;
 pub mod trs;
+pub(crate) mod up;
+
+pub use up::CholeskyElement;
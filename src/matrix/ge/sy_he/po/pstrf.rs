@@ -0,0 +1,258 @@
+use crate::matrix::ge::Matrix;
+use crate::matrix::MatrixError;
+use crate::number::c64;
+use crate::{Number, PermutationMatrix};
+use lapack::{dpstrf, zpstrf};
+use std::error::Error;
+
+/// Lower factor `L`, permutation `P` and the computed rank, where `P * A * P^T = L * L^*`.
+#[derive(Clone, Debug)]
+pub struct PSTRF<T = f64>(pub Matrix<T>, pub PermutationMatrix, pub usize)
+where
+    T: Number;
+
+impl Matrix {
+    /// # Pivoted Cholesky decomposition
+    /// for positive semidefinite f64 matrix
+    ///
+    /// Unlike `potrf`, semidefinite matrices are factorized up to their numerical rank.
+    /// - `tol`: Diagonal elements below `tol` are treated as zero. If negative, `n * eps * max(diag)` is used.
+    pub fn pstrf(self, tol: f64) -> Result<PSTRF, MatrixError> {
+        let n = self.rows;
+        if n != self.cols {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let mut slf = self;
+        let mut piv = vec![0; n];
+        let mut rank = 0;
+        let mut work = vec![0.0; 2 * n];
+        let mut info = 0;
+
+        unsafe {
+            dpstrf(
+                b'L',
+                n as i32,
+                &mut slf.elems,
+                n.max(1) as i32,
+                &mut piv,
+                &mut rank,
+                tol,
+                &mut work,
+                &mut info,
+            );
+        }
+
+        // info == 1 means rank deficiency, which is not an error.
+        match info {
+            0 | 1 => {
+                let perm = piv.iter().map(|&p| p as usize - 1).collect();
+                Ok(PSTRF(slf, PermutationMatrix::new(perm)?, rank as usize))
+            }
+            _ => Err(MatrixError::LapackRoutineError {
+                routine: "dpstrf".to_owned(),
+                info,
+            }),
+        }
+    }
+}
+
+impl Matrix<c64> {
+    /// # Pivoted Cholesky decomposition
+    /// for positive semidefinite c64 matrix
+    ///
+    /// Unlike `potrf`, semidefinite matrices are factorized up to their numerical rank.
+    /// - `tol`: Diagonal elements below `tol` are treated as zero. If negative, `n * eps * max(diag)` is used.
+    pub fn pstrf(self, tol: f64) -> Result<PSTRF<c64>, MatrixError> {
+        let n = self.rows;
+        if n != self.cols {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let mut slf = self;
+        let mut piv = vec![0; n];
+        let mut rank = 0;
+        let mut work = vec![0.0; 2 * n];
+        let mut info = 0;
+
+        unsafe {
+            zpstrf(
+                b'L',
+                n as i32,
+                &mut slf.elems,
+                n.max(1) as i32,
+                &mut piv,
+                &mut rank,
+                tol,
+                &mut work,
+                &mut info,
+            );
+        }
+
+        // info == 1 means rank deficiency, which is not an error.
+        match info {
+            0 | 1 => {
+                let perm = piv.iter().map(|&p| p as usize - 1).collect();
+                Ok(PSTRF(slf, PermutationMatrix::new(perm)?, rank as usize))
+            }
+            _ => Err(MatrixError::LapackRoutineError {
+                routine: "zpstrf".to_owned(),
+                info,
+            }),
+        }
+    }
+}
+
+impl<T> PSTRF<T>
+where
+    T: Number,
+{
+    /// Lower factor `L` with `rank` columns, where `P * A * P^T = L * L^*`.
+    pub fn l(&self) -> Matrix<T> {
+        let PSTRF(mat, _, rank) = self;
+
+        Matrix::from_fn(mat.rows, *rank, |i, j| {
+            if i >= j {
+                mat[(i, j)]
+            } else {
+                T::default()
+            }
+        })
+    }
+
+    /// Permutation `P`, where `P * A * P^T = L * L^*`.
+    pub fn p(&self) -> &PermutationMatrix {
+        &self.1
+    }
+
+    /// Computed rank.
+    pub fn rank(&self) -> usize {
+        self.2
+    }
+}
+
+impl Matrix {
+    /// # Partial pivoted Cholesky decomposition
+    /// for positive semidefinite matrix which is accessed only through its diagonal and columns.
+    /// only k iteration
+    ///
+    /// Returns `(L, pivots)` where `L` has at most `k` columns and `A ≈ L * L^T`.
+    /// - `diag`: `diag(i)` is the element `(i, i)`.
+    /// - `col`: `col(j)` is the column `j` with `n` elements.
+    /// - `tol`: Stops when the trace of the residual `A - L * L^T` is below `tol`.
+    pub fn pstrf_k(
+        n: usize,
+        k: usize,
+        diag: &dyn Fn(usize) -> Result<f64, Box<dyn Error + Send + Sync>>,
+        col: &dyn Fn(usize) -> Result<Vec<f64>, Box<dyn Error + Send + Sync>>,
+        tol: f64,
+    ) -> Result<(Matrix, Vec<usize>), MatrixError> {
+        let k = k.min(n);
+        let mut d = (0..n).map(diag).collect::<Result<Vec<_>, _>>()?;
+        let mut l = Matrix::new(n, k);
+        let mut pivots = Vec::with_capacity(k);
+
+        for m in 0..k {
+            let (i, &max) = match d
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| !pivots.contains(i))
+                .max_by(|a, b| a.1.partial_cmp(b.1).unwrap_or(std::cmp::Ordering::Equal))
+            {
+                Some(max) => max,
+                None => break,
+            };
+            if d.iter().sum::<f64>() < tol || max <= 0.0 {
+                break;
+            }
+
+            let a = col(i)?;
+            if a.len() != n {
+                return Err(MatrixError::DimensionMismatch);
+            }
+
+            let sqrt_max = max.sqrt();
+            for j in 0..n {
+                let lj = (a[j] - (0..m).map(|p| l[(j, p)] * l[(i, p)]).sum::<f64>()) / sqrt_max;
+                l[(j, m)] = lj;
+                d[j] -= lj * lj;
+            }
+            d[i] = 0.0;
+            pivots.push(i);
+        }
+
+        let rank = pivots.len();
+        l.elems.truncate(n * rank);
+        l.cols = rank;
+
+        Ok((l, pivots))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    #[test]
+    fn it_works() {
+        let x = mat!(
+            1.0, 2.0;
+            0.0, 1.0;
+            3.0, 1.0
+        );
+        let a = x.dot(&x.t());
+        let pstrf = a.clone().pstrf(-1.0).unwrap();
+        let l = pstrf.l();
+
+        assert_eq!(pstrf.rank(), 2);
+        assert_eq!(l.cols(), 2);
+
+        let pap = a
+            .permute_rows(pstrf.p())
+            .unwrap()
+            .permute_cols(pstrf.p())
+            .unwrap();
+        let llt = l.dot(&l.t());
+
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((pap[(i, j)] - llt[(i, j)]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn pstrf_k() {
+        let x = [0.0f64, 0.1, 0.2, 2.0, 2.1];
+        let kernel = |i: usize, j: usize| (-(x[i] - x[j]).powi(2)).exp();
+        let a = Matrix::from_fn(5, 5, kernel);
+
+        let (l, pivots) = Matrix::pstrf_k(
+            5,
+            5,
+            &|i| Ok(kernel(i, i)),
+            &|j| Ok((0..5).map(|i| kernel(i, j)).collect()),
+            0.0,
+        )
+        .unwrap();
+        let llt = l.dot(&l.t());
+
+        assert_eq!(pivots.len(), 5);
+        for i in 0..5 {
+            for j in 0..5 {
+                assert!((a[(i, j)] - llt[(i, j)]).abs() < 1e-10);
+            }
+        }
+
+        let (l, pivots) = Matrix::pstrf_k(
+            5,
+            2,
+            &|i| Ok(kernel(i, i)),
+            &|j| Ok((0..5).map(|i| kernel(i, j)).collect()),
+            0.0,
+        )
+        .unwrap();
+
+        assert_eq!(l.cols(), 2);
+        assert!(pivots[0] < 3 && 3 <= pivots[1] || pivots[1] < 3 && 3 <= pivots[0]);
+    }
+}
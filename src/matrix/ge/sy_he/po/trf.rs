@@ -70,3 +70,134 @@ impl Matrix<c64> {
         }
     }
 }
+
+impl Matrix {
+    /// # Cholesky decomposition with jitter
+    /// for numerically semidefinite f64 matrix
+    ///
+    /// Tries `potrf` first, then retries with `jitter * 10^k` added to the diagonal for `k = 0..max_tries`.
+    /// Returns the factor and the jitter added, which is `0.0` if `potrf` succeeded at first.
+    pub fn potrf_jitter(self, jitter: f64, max_tries: usize) -> Result<(POTRF, f64), MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let mut result = self.clone().potrf().map(|l| (l, 0.0));
+        let mut added = jitter;
+
+        for _ in 0..max_tries {
+            match result {
+                Err(MatrixError::LapackRoutineError { .. })
+                | Err(MatrixError::NotPositiveDefinite { .. }) => {}
+                _ => break,
+            }
+
+            let mut a = self.clone();
+            for i in 0..a.rows {
+                a[(i, i)] += added;
+            }
+            result = a.potrf().map(|l| (l, added));
+            added *= 10.0;
+        }
+
+        result
+    }
+}
+
+impl Matrix<c64> {
+    /// # Cholesky decomposition with jitter
+    /// for numerically semidefinite c64 matrix
+    ///
+    /// Tries `potrf` first, then retries with `jitter * 10^k` added to the diagonal for `k = 0..max_tries`.
+    /// Returns the factor and the jitter added, which is `0.0` if `potrf` succeeded at first.
+    pub fn potrf_jitter(
+        self,
+        jitter: f64,
+        max_tries: usize,
+    ) -> Result<(POTRF<c64>, f64), MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let mut result = self.clone().potrf().map(|l| (l, 0.0));
+        let mut added = jitter;
+
+        for _ in 0..max_tries {
+            match result {
+                Err(MatrixError::LapackRoutineError { .. })
+                | Err(MatrixError::NotPositiveDefinite { .. }) => {}
+                _ => break,
+            }
+
+            let mut a = self.clone();
+            for i in 0..a.rows {
+                a[(i, i)] += added;
+            }
+            result = a.potrf().map(|l| (l, added));
+            added *= 10.0;
+        }
+
+        result
+    }
+}
+
+impl<T> POTRF<T>
+where
+    T: Number,
+{
+    /// Lower triangular `L` with the upper triangle zeroed, where `A = L * L^*`.
+    pub fn l(&self) -> Matrix<T> {
+        let POTRF(mat) = self;
+
+        Matrix::from_fn(mat.rows, mat.cols, |i, j| {
+            if i >= j {
+                mat[(i, j)]
+            } else {
+                T::default()
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    #[test]
+    fn it_works() {
+        let a = mat!(
+            4.0, 2.0;
+            2.0, 5.0
+        );
+        let l = a.clone().potrf().unwrap().l();
+
+        assert_eq!(l, mat!(2.0, 0.0; 1.0, 2.0));
+        assert_eq!(l.dot(&l.t()), a);
+    }
+
+    #[test]
+    fn jitter() {
+        let a = mat!(
+            1.0, 1.0;
+            1.0, 1.0
+        );
+
+        assert!(a.clone().potrf().is_err());
+
+        let (l, jitter) = a.potrf_jitter(1e-10, 5).unwrap();
+
+        assert!(0.0 < jitter);
+        assert!(l.l()[(1, 1)] > 0.0);
+
+        let (_, jitter) = mat!(2.0, 1.0; 1.0, 2.0).potrf_jitter(1e-10, 5).unwrap();
+
+        assert_eq!(jitter, 0.0);
+
+        let b = mat!(
+            1.0, 0.0;
+            0.0, 1.0;
+            0.0, 0.0
+        );
+
+        assert!(b.potrf_jitter(1e-10, 5).is_err());
+    }
+}
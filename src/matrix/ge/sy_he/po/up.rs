@@ -0,0 +1,176 @@
+use super::trf::POTRF;
+use crate::matrix::MatrixError;
+use crate::number::{c64, Number};
+use std::ops::Div;
+
+/// Elements of the Cholesky factors which can be updated in place.
+pub trait CholeskyElement: Number + Div<Output = Self> {
+    fn conj(self) -> Self;
+    fn norm_sqr(self) -> f64;
+    fn re(self) -> f64;
+    fn from_re(re: f64) -> Self;
+}
+
+impl CholeskyElement for f64 {
+    fn conj(self) -> Self {
+        self
+    }
+
+    fn norm_sqr(self) -> f64 {
+        self * self
+    }
+
+    fn re(self) -> f64 {
+        self
+    }
+
+    fn from_re(re: f64) -> Self {
+        re
+    }
+}
+
+impl CholeskyElement for c64 {
+    fn conj(self) -> Self {
+        c64::conj(&self)
+    }
+
+    fn norm_sqr(self) -> f64 {
+        c64::norm_sqr(&self)
+    }
+
+    fn re(self) -> f64 {
+        self.re
+    }
+
+    fn from_re(re: f64) -> Self {
+        c64::new(re, 0.0)
+    }
+}
+
+/// Refactors `L * L^* + v * v^*` (or `- v * v^*` if `downdate`) in place by Givens-like rotations in O(n^2).
+/// `index(i, j)` is the position of `L[(i, j)]` in `elems`, so that full and packed storages share this.
+pub(crate) fn rank1_update<T, F>(
+    elems: &mut [T],
+    n: usize,
+    index: F,
+    v: &[T],
+    downdate: bool,
+) -> Result<(), MatrixError>
+where
+    T: CholeskyElement,
+    F: Fn(usize, usize) -> usize,
+{
+    if v.len() != n {
+        return Err(MatrixError::DimensionMismatch);
+    }
+
+    if downdate {
+        // `L * L^* - v * v^*` is positive definite iff `|L^-1 * v| < 1`. Checked first to keep `elems` intact on error.
+        let mut p = v.to_vec();
+        let mut norm_sqr = 0.0;
+        for i in 0..n {
+            let pi = p[i] / elems[index(i, i)];
+            p[i] = pi;
+            norm_sqr += pi.norm_sqr();
+            for k in i + 1..n {
+                p[k] -= elems[index(k, i)] * pi;
+            }
+        }
+        if norm_sqr.is_nan() || norm_sqr >= 1.0 {
+            return Err(MatrixError::NotPositiveDefinite {
+                index: n.max(1) - 1,
+            });
+        }
+    }
+
+    let sign = if downdate { -1.0 } else { 1.0 };
+    let mut x = v.to_vec();
+
+    for k in 0..n {
+        let lkk = elems[index(k, k)].re();
+        let r_sqr = lkk * lkk + sign * x[k].norm_sqr();
+        if r_sqr.is_nan() || r_sqr <= 0.0 {
+            return Err(MatrixError::NotPositiveDefinite { index: k });
+        }
+
+        let r = r_sqr.sqrt();
+        let c = T::from_re(r / lkk);
+        let s = x[k] / T::from_re(lkk);
+        elems[index(k, k)] = T::from_re(r);
+
+        for i in k + 1..n {
+            let lik = (elems[index(i, k)] + T::from_re(sign) * s.conj() * x[i]) / c;
+            elems[index(i, k)] = lik;
+            x[i] = c * x[i] - s * lik;
+        }
+    }
+
+    Ok(())
+}
+
+impl<T> POTRF<T>
+where
+    T: CholeskyElement,
+{
+    /// # Rank-1 update
+    /// Refactors `A + v * v^*` in place in O(n^2) without calling `potrf` again.
+    pub fn update(&mut self, v: &[T]) -> Result<(), MatrixError> {
+        let n = self.0.rows();
+        let elems = self.0.elems_mut();
+
+        rank1_update(elems, n, |i, j| i + j * n, v, false)
+    }
+
+    /// # Rank-1 downdate
+    /// Refactors `A - v * v^*` in place in O(n^2).
+    /// Returns `MatrixError::NotPositiveDefinite` and leaves `self` unchanged if the result would not be positive definite.
+    pub fn downdate(&mut self, v: &[T]) -> Result<(), MatrixError> {
+        let n = self.0.rows();
+        let elems = self.0.elems_mut();
+
+        rank1_update(elems, n, |i, j| i + j * n, v, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    #[test]
+    fn it_works() {
+        let a = mat!(
+            4.0, 2.0, 1.0;
+            2.0, 5.0, 3.0;
+            1.0, 3.0, 6.0
+        );
+        let v = [1.0, -2.0, 0.5];
+        let b = a.clone() + v.to_vec().col_mat().dot(&v.to_vec().row_mat());
+
+        let mut l = a.clone().potrf().unwrap();
+        l.update(&v).unwrap();
+        let expected = b.clone().potrf().unwrap().l();
+        let actual = l.l();
+
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((actual[(i, j)] - expected[(i, j)]).abs() < 1e-12);
+            }
+        }
+
+        l.downdate(&v).unwrap();
+        let expected = a.potrf().unwrap().l();
+        let actual = l.l();
+
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((actual[(i, j)] - expected[(i, j)]).abs() < 1e-12);
+            }
+        }
+
+        let before = l.l();
+        match l.downdate(&[10.0, 0.0, 0.0]) {
+            Err(MatrixError::NotPositiveDefinite { .. }) => {}
+            _ => panic!(),
+        }
+        assert_eq!(l.l(), before);
+    }
+}
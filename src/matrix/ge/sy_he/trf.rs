@@ -2,6 +2,7 @@ use crate::matrix::ge::Matrix;
 use crate::matrix::MatrixError;
 use crate::number::c64;
 use crate::Number;
+use crate::PermutationMatrix;
 use lapack::{dsytrf, zhetrf, zsytrf};
 use serde::{Deserialize, Serialize};
 
@@ -123,3 +124,98 @@ impl Matrix<c64> {
         }
     }
 }
+
+/// Converts the output of `?sytrf`/`?hetrf` with `uplo = 'L'` to `(L, D, P)` where `P * A * P^T = L * D * L^T`.
+/// `conj` is applied to the upper element of each 2x2 block of `D`.
+pub(crate) fn ldl_from_bunch_kaufman<T>(
+    mat: &Matrix<T>,
+    ipiv: &[i32],
+    conj: fn(T) -> T,
+) -> (Matrix<T>, Matrix<T>, PermutationMatrix)
+where
+    T: Number,
+{
+    let n = mat.rows;
+    let mut l = Matrix::<T>::identity(n);
+    let mut d = Matrix::<T>::new(n, n);
+
+    // LAPACK stores L = P(1) * L(1) * ... * P(k) * L(k) without applying the later interchanges to the earlier columns.
+    let mut k = 0;
+    while k < n {
+        let step = if ipiv[k] > 0 { 1 } else { 2 };
+        let kp = (ipiv[k].abs() - 1) as usize;
+        let kk = k + step - 1;
+
+        if kp != kk {
+            for j in 0..k {
+                l.elems.swap(kk + j * n, kp + j * n);
+            }
+        }
+
+        d[(k, k)] = mat[(k, k)];
+        if step == 2 {
+            d[(k + 1, k)] = mat[(k + 1, k)];
+            d[(k, k + 1)] = conj(mat[(k + 1, k)]);
+            d[(k + 1, k + 1)] = mat[(k + 1, k + 1)];
+        }
+
+        for j in k..k + step {
+            for i in k + step..n {
+                l[(i, j)] = mat[(i, j)];
+            }
+        }
+
+        k += step;
+    }
+
+    (l, d, PermutationMatrix::from_sytrf_ipiv(ipiv).unwrap())
+}
+
+impl<T> SYTRF<T>
+where
+    T: Number,
+{
+    /// Returns `(L, D, P)` where `P * A * P^T = L * D * L^T`,
+    /// `L` is unit lower triangular and `D` is block diagonal with 1x1 and 2x2 blocks.
+    pub fn ldl(&self) -> (Matrix<T>, Matrix<T>, PermutationMatrix) {
+        ldl_from_bunch_kaufman(&self.0, &self.1, |e| e)
+    }
+}
+
+impl HETRF {
+    /// Returns `(L, D, P)` where `P * A * P^T = L * D * L^*`,
+    /// `L` is unit lower triangular and `D` is Hermitian block diagonal with 1x1 and 2x2 blocks.
+    pub fn ldl(&self) -> (Matrix<c64>, Matrix<c64>, PermutationMatrix) {
+        ldl_from_bunch_kaufman(&self.0, &self.1, |e| e.conj())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    #[test]
+    fn it_works() {
+        let a = mat!(
+            0.0, 1.0, 2.0, 3.0;
+            1.0, 0.0, 4.0, 5.0;
+            2.0, 4.0, 1.0, 6.0;
+            3.0, 5.0, 6.0, 0.0
+        );
+        let (l, d, p) = a.clone().sytrf().unwrap().ldl();
+        let p = p.mat::<f64>();
+        let pap = p.dot(&a).dot(&p.t());
+        let ldl = l.dot(&d).dot(&l.t());
+
+        for i in 0..4 {
+            assert_eq!(l[(i, i)], 1.0);
+            for j in i + 1..4 {
+                assert_eq!(l[(i, j)], 0.0);
+            }
+        }
+        for i in 0..4 {
+            for j in 0..4 {
+                assert!((pap[(i, j)] - ldl[(i, j)]).abs() < 1e-12);
+            }
+        }
+    }
+}
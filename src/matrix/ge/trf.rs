@@ -2,6 +2,7 @@ use crate::matrix::ge::Matrix;
 use crate::matrix::MatrixError;
 use crate::number::c64;
 use crate::Number;
+use crate::PermutationMatrix;
 use lapack::{dgetrf, zgetrf};
 use serde::{Deserialize, Serialize};
 
@@ -63,3 +64,72 @@ impl Matrix<c64> {
         }
     }
 }
+
+impl<T> GETRF<T>
+where
+    T: Number,
+{
+    /// Unit lower triangular `L` with `min(rows, cols)` columns, where `P * A = L * U`.
+    pub fn l(&self) -> Matrix<T> {
+        let GETRF(mat, _) = self;
+        let k = mat.rows.min(mat.cols);
+
+        Matrix::from_fn(mat.rows, k, |i, j| {
+            if i == j {
+                T::one()
+            } else if i > j {
+                mat[(i, j)]
+            } else {
+                T::default()
+            }
+        })
+    }
+
+    /// Upper triangular `U` with `min(rows, cols)` rows, where `P * A = L * U`.
+    pub fn u(&self) -> Matrix<T> {
+        let GETRF(mat, _) = self;
+        let k = mat.rows.min(mat.cols);
+
+        Matrix::from_fn(
+            k,
+            mat.cols,
+            |i, j| if i <= j { mat[(i, j)] } else { T::default() },
+        )
+    }
+
+    /// Row permutation `P`, where `P * A = L * U`.
+    pub fn p(&self) -> PermutationMatrix {
+        let GETRF(mat, ipiv) = self;
+
+        PermutationMatrix::from_ipiv(mat.rows, ipiv).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    #[test]
+    fn it_works() {
+        let a = mat!(
+            1.0, 2.0, 3.0;
+            4.0, 5.0, 6.0;
+            7.0, 8.0, 10.0
+        );
+        let lu = a.clone().getrf().unwrap();
+        let l = lu.l();
+        let u = lu.u();
+
+        assert_eq!(l[(0, 0)], 1.0);
+        assert_eq!(l[(0, 1)], 0.0);
+        assert_eq!(u[(1, 0)], 0.0);
+
+        let pa = lu.p().mat::<f64>().dot(&a);
+        let l_u = l.dot(&u);
+
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((pa[(i, j)] - l_u[(i, j)]).abs() < 1e-12);
+            }
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use super::{MatrixView, MatrixViewMut};
+use crate::matrix::MatrixError;
+use crate::{number::c64, Matrix};
+use blas::{dgemm, zgemm};
+
+macro_rules! impl_view_mm {
+    {$t: ty, $gemm: ident, $one: expr, $zero: expr} => {
+        impl<'a> MatrixView<'a, $t> {
+            /// # Matrix product
+            /// Panics if `self.cols() != rhs.rows()`, as `Matrix::dot` does.
+            pub fn dot(&self, rhs: &MatrixView<$t>) -> Matrix<$t> {
+                let mut new_matrix = Matrix::<$t>::new(self.rows, rhs.cols);
+
+                new_matrix
+                    .as_view_mut()
+                    .gemm(self, rhs, $one, $zero)
+                    .expect("Dimension mismatch.");
+
+                new_matrix
+            }
+        }
+
+        impl<'a> MatrixViewMut<'a, $t> {
+            /// C = self
+            /// A = lhs
+            /// B = rhs
+            /// self = alpha*A*B + beta*C
+            pub fn gemm(
+                &mut self,
+                lhs: &MatrixView<$t>,
+                rhs: &MatrixView<$t>,
+                alpha: $t,
+                beta: $t,
+            ) -> Result<(), MatrixError> {
+                if self.rows != lhs.rows || self.cols != rhs.cols || lhs.cols != rhs.rows {
+                    return Err(MatrixError::DimensionMismatch);
+                }
+                if self.rows == 0 || self.cols == 0 {
+                    return Ok(());
+                }
+
+                unsafe {
+                    $gemm(
+                        b'N',
+                        b'N',
+                        self.rows as i32,
+                        self.cols as i32,
+                        lhs.cols as i32,
+                        alpha,
+                        lhs.elems,
+                        lhs.ld as i32,
+                        rhs.elems,
+                        rhs.ld as i32,
+                        beta,
+                        self.elems,
+                        self.ld as i32,
+                    );
+                }
+
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_view_mm! {f64, dgemm, 1.0, 0.0}
+impl_view_mm! {c64, zgemm, c64::new(1.0, 0.0), c64::new(0.0, 0.0)}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    #[test]
+    fn it_works() {
+        let a = mat!(
+            1.0, 2.0, 3.0;
+            4.0, 5.0, 6.0;
+            7.0, 8.0, 9.0
+        );
+        let a11 = a.view(0, 0, 2, 2).unwrap();
+        let a12 = a.view(0, 2, 2, 1).unwrap();
+
+        assert_eq!(a11.dot(&a12), a11.to_matrix().dot(&a12.to_matrix()));
+
+        let mut c = Matrix::<f64>::new(4, 4);
+        c.view_mut(1, 1, 2, 1)
+            .unwrap()
+            .gemm(&a11, &a12, 2.0, 0.0)
+            .unwrap();
+
+        assert_eq!(c[(1, 1)], 2.0 * (1.0 * 3.0 + 2.0 * 6.0));
+        assert_eq!(c[(2, 1)], 2.0 * (4.0 * 3.0 + 5.0 * 6.0));
+        assert_eq!(c.elems().iter().filter(|&&e| e != 0.0).count(), 2);
+    }
+}
@@ -0,0 +1,502 @@
+pub mod mm;
+pub mod slice;
+pub mod trf;
+pub mod trs;
+
+pub use slice::SliceIndex;
+
+use crate::matrix::ge::Matrix;
+use crate::matrix::MatrixError;
+use crate::number::Number;
+use std::ops::{Index, IndexMut, Range};
+
+/// # Matrix view
+/// Borrowed column-major block with the leading dimension `ld`.
+/// The element `(i, j)` is `elems[i + j * ld]`.
+/// ```
+/// use opensrdk_linear_algebra::*;
+///
+/// let a = mat!(
+///   1.0, 2.0, 3.0;
+///   4.0, 5.0, 6.0;
+///   7.0, 8.0, 9.0
+/// );
+/// let v = a.view(1, 1, 2, 2).unwrap();
+///
+/// assert_eq!(v.ld(), 3);
+/// assert_eq!(v[(0, 1)], 6.0);
+/// assert_eq!(v.to_matrix(), mat!(5.0, 6.0; 8.0, 9.0));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct MatrixView<'a, T = f64>
+where
+    T: Number,
+{
+    rows: usize,
+    cols: usize,
+    ld: usize,
+    elems: &'a [T],
+}
+
+/// # Mutable matrix view
+/// Mutably borrowed version of `MatrixView`.
+#[derive(Debug)]
+pub struct MatrixViewMut<'a, T = f64>
+where
+    T: Number,
+{
+    rows: usize,
+    cols: usize,
+    ld: usize,
+    elems: &'a mut [T],
+}
+
+/// Range of `elems` covered by the block.
+fn block_range(
+    size: (usize, usize),
+    ld: usize,
+    start_i: usize,
+    start_j: usize,
+    rows: usize,
+    cols: usize,
+) -> Result<Range<usize>, MatrixError> {
+    let end_i = start_i
+        .checked_add(rows)
+        .ok_or(MatrixError::DimensionMismatch)?;
+    let end_j = start_j
+        .checked_add(cols)
+        .ok_or(MatrixError::DimensionMismatch)?;
+    if size.0 < end_i || size.1 < end_j {
+        return Err(MatrixError::DimensionMismatch);
+    }
+    if rows == 0 || cols == 0 {
+        return Ok(0..0);
+    }
+
+    let start = start_j
+        .checked_mul(ld)
+        .and_then(|s| s.checked_add(start_i))
+        .ok_or(MatrixError::DimensionMismatch)?;
+    let end = (cols - 1)
+        .checked_mul(ld)
+        .and_then(|e| e.checked_add(start))
+        .and_then(|e| e.checked_add(rows))
+        .ok_or(MatrixError::DimensionMismatch)?;
+
+    Ok(start..end)
+}
+
+impl<T> Matrix<T>
+where
+    T: Number,
+{
+    /// View of the whole matrix.
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        MatrixView {
+            rows: self.rows,
+            cols: self.cols,
+            ld: self.rows.max(1),
+            elems: &self.elems,
+        }
+    }
+
+    pub fn as_view_mut(&mut self) -> MatrixViewMut<'_, T> {
+        MatrixViewMut {
+            rows: self.rows,
+            cols: self.cols,
+            ld: self.rows.max(1),
+            elems: &mut self.elems,
+        }
+    }
+
+    /// # View
+    /// of the block starting at `(start_i, start_j)` without copying.
+    pub fn view(
+        &self,
+        start_i: usize,
+        start_j: usize,
+        rows: usize,
+        cols: usize,
+    ) -> Result<MatrixView<'_, T>, MatrixError> {
+        self.as_view().into_view(start_i, start_j, rows, cols)
+    }
+
+    pub fn view_mut(
+        &mut self,
+        start_i: usize,
+        start_j: usize,
+        rows: usize,
+        cols: usize,
+    ) -> Result<MatrixViewMut<'_, T>, MatrixError> {
+        self.as_view_mut()
+            .into_view_mut(start_i, start_j, rows, cols)
+    }
+}
+
+impl<'a, T> MatrixView<'a, T>
+where
+    T: Number,
+{
+    /// - `ld`: Leading dimension. Must be at least `rows`.
+    /// - `elems`: Column-major elements. The length must be at least `(cols - 1) * ld + rows`.
+    pub fn from(rows: usize, cols: usize, ld: usize, elems: &'a [T]) -> Result<Self, MatrixError> {
+        if ld < rows.max(1) || elems.len() < block_range((rows, cols), ld, 0, 0, rows, cols)?.end {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        Ok(Self {
+            rows,
+            cols,
+            ld,
+            elems,
+        })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Leading dimension.
+    pub fn ld(&self) -> usize {
+        self.ld
+    }
+
+    /// Elements starting at `(0, 0)`, including the gaps between columns.
+    pub fn elems(&self) -> &'a [T] {
+        self.elems
+    }
+
+    /// View of the block of this view, with the same lifetime.
+    pub fn into_view(
+        self,
+        start_i: usize,
+        start_j: usize,
+        rows: usize,
+        cols: usize,
+    ) -> Result<MatrixView<'a, T>, MatrixError> {
+        let range = block_range(
+            (self.rows, self.cols),
+            self.ld,
+            start_i,
+            start_j,
+            rows,
+            cols,
+        )?;
+
+        Ok(MatrixView {
+            rows,
+            cols,
+            ld: self.ld,
+            elems: &self.elems[range],
+        })
+    }
+
+    /// Column `j` as a contiguous slice.
+    pub fn col(&self, j: usize) -> &'a [T] {
+        &self.elems[j * self.ld..j * self.ld + self.rows]
+    }
+
+    /// Copies the view into a new matrix.
+    pub fn to_matrix(&self) -> Matrix<T> {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            elems: (0..self.cols)
+                .flat_map(|j| self.col(j).iter().copied())
+                .collect(),
+        }
+    }
+}
+
+impl<'a, T> From<&'a Matrix<T>> for MatrixView<'a, T>
+where
+    T: Number,
+{
+    fn from(m: &'a Matrix<T>) -> Self {
+        m.as_view()
+    }
+}
+
+impl<'a, T> MatrixViewMut<'a, T>
+where
+    T: Number,
+{
+    /// - `ld`: Leading dimension. Must be at least `rows`.
+    /// - `elems`: Column-major elements. The length must be at least `(cols - 1) * ld + rows`.
+    pub fn from(
+        rows: usize,
+        cols: usize,
+        ld: usize,
+        elems: &'a mut [T],
+    ) -> Result<Self, MatrixError> {
+        if ld < rows.max(1) || elems.len() < block_range((rows, cols), ld, 0, 0, rows, cols)?.end {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        Ok(Self {
+            rows,
+            cols,
+            ld,
+            elems,
+        })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Leading dimension.
+    pub fn ld(&self) -> usize {
+        self.ld
+    }
+
+    pub fn elems(&self) -> &[T] {
+        self.elems
+    }
+
+    pub fn elems_mut(&mut self) -> &mut [T] {
+        self.elems
+    }
+
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        MatrixView {
+            rows: self.rows,
+            cols: self.cols,
+            ld: self.ld,
+            elems: self.elems,
+        }
+    }
+
+    /// Reborrows the view.
+    pub fn as_view_mut(&mut self) -> MatrixViewMut<'_, T> {
+        MatrixViewMut {
+            rows: self.rows,
+            cols: self.cols,
+            ld: self.ld,
+            elems: self.elems,
+        }
+    }
+
+    pub fn view(
+        &self,
+        start_i: usize,
+        start_j: usize,
+        rows: usize,
+        cols: usize,
+    ) -> Result<MatrixView<'_, T>, MatrixError> {
+        self.as_view().into_view(start_i, start_j, rows, cols)
+    }
+
+    pub fn view_mut(
+        &mut self,
+        start_i: usize,
+        start_j: usize,
+        rows: usize,
+        cols: usize,
+    ) -> Result<MatrixViewMut<'_, T>, MatrixError> {
+        self.as_view_mut()
+            .into_view_mut(start_i, start_j, rows, cols)
+    }
+
+    /// Mutable view of the block of this view, with the same lifetime.
+    pub fn into_view_mut(
+        self,
+        start_i: usize,
+        start_j: usize,
+        rows: usize,
+        cols: usize,
+    ) -> Result<MatrixViewMut<'a, T>, MatrixError> {
+        let range = block_range(
+            (self.rows, self.cols),
+            self.ld,
+            start_i,
+            start_j,
+            rows,
+            cols,
+        )?;
+
+        Ok(MatrixViewMut {
+            rows,
+            cols,
+            ld: self.ld,
+            elems: &mut self.elems[range],
+        })
+    }
+
+    /// Splits into the columns `[0, j)` and `[j, cols)`, which can be mutated at the same time.
+    pub fn split_at_col(self, j: usize) -> (MatrixViewMut<'a, T>, MatrixViewMut<'a, T>) {
+        if self.cols < j {
+            panic!("Dimension mismatch.")
+        }
+
+        let mid = (j * self.ld).min(self.elems.len());
+        let (left, right) = self.elems.split_at_mut(mid);
+
+        (
+            MatrixViewMut {
+                rows: self.rows,
+                cols: j,
+                ld: self.ld,
+                elems: left,
+            },
+            MatrixViewMut {
+                rows: self.rows,
+                cols: self.cols - j,
+                ld: self.ld,
+                elems: right,
+            },
+        )
+    }
+
+    pub fn col(&self, j: usize) -> &[T] {
+        &self.elems[j * self.ld..j * self.ld + self.rows]
+    }
+
+    pub fn col_mut(&mut self, j: usize) -> &mut [T] {
+        &mut self.elems[j * self.ld..j * self.ld + self.rows]
+    }
+
+    /// Copies `src` into this view.
+    pub fn copy_from(&mut self, src: &MatrixView<T>) -> Result<(), MatrixError> {
+        if self.rows != src.rows || self.cols != src.cols {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        for j in 0..self.cols {
+            self.col_mut(j).copy_from_slice(src.col(j));
+        }
+
+        Ok(())
+    }
+
+    pub fn fill(&mut self, value: T) {
+        for j in 0..self.cols {
+            self.col_mut(j).iter_mut().for_each(|e| *e = value);
+        }
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T> {
+        self.as_view().to_matrix()
+    }
+}
+
+impl<'a, T> From<&'a mut Matrix<T>> for MatrixViewMut<'a, T>
+where
+    T: Number,
+{
+    fn from(m: &'a mut Matrix<T>) -> Self {
+        m.as_view_mut()
+    }
+}
+
+/// Compares the shapes and the elements column by column, ignoring `ld` and the gaps between columns.
+impl<'a, 'b, T> PartialEq<MatrixView<'b, T>> for MatrixView<'a, T>
+where
+    T: Number,
+{
+    fn eq(&self, other: &MatrixView<'b, T>) -> bool {
+        self.rows == other.rows
+            && self.cols == other.cols
+            && (0..self.cols).all(|j| self.col(j) == other.col(j))
+    }
+}
+
+impl<'a, 'b, T> PartialEq<MatrixViewMut<'b, T>> for MatrixViewMut<'a, T>
+where
+    T: Number,
+{
+    fn eq(&self, other: &MatrixViewMut<'b, T>) -> bool {
+        self.as_view() == other.as_view()
+    }
+}
+
+impl<'a, T> Index<(usize, usize)> for MatrixView<'a, T>
+where
+    T: Number,
+{
+    type Output = T;
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        &self.col(index.1)[index.0]
+    }
+}
+
+impl<'a, T> Index<(usize, usize)> for MatrixViewMut<'a, T>
+where
+    T: Number,
+{
+    type Output = T;
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        &self.col(index.1)[index.0]
+    }
+}
+
+impl<'a, T> IndexMut<(usize, usize)> for MatrixViewMut<'a, T>
+where
+    T: Number,
+{
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        &mut self.col_mut(index.1)[index.0]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    #[test]
+    fn it_works() {
+        let mut a = mat!(
+            1.0, 2.0, 3.0;
+            4.0, 5.0, 6.0;
+            7.0, 8.0, 9.0
+        );
+        let v = a.view(1, 0, 2, 3).unwrap();
+
+        assert_eq!(v.ld(), 3);
+        assert_eq!(v[(1, 2)], 9.0);
+        assert_eq!(v.into_view(0, 1, 1, 2).unwrap().to_matrix(), mat!(5.0, 6.0));
+        assert!(a.view(2, 2, 2, 1).is_err());
+        assert!(a.view(usize::MAX, 0, 2, 1).is_err());
+        assert!(a.view(0, 1, 1, usize::MAX).is_err());
+        assert!(MatrixView::from(1, 2, usize::MAX, a.elems()).is_err());
+
+        let (mut left, mut right) = a.as_view_mut().split_at_col(1);
+        right.view_mut(0, 1, 3, 1).unwrap().fill(0.0);
+        left[(2, 0)] = -7.0;
+
+        assert_eq!(
+            a,
+            mat!(
+                1.0, 2.0, 0.0;
+                4.0, 5.0, 0.0;
+                -7.0, 8.0, 0.0
+            )
+        );
+
+        let b = mat!(10.0, 20.0);
+        a.view_mut(1, 1, 1, 2)
+            .unwrap()
+            .copy_from(&b.as_view())
+            .unwrap();
+
+        assert_eq!(a.eject_row(1), vec![4.0, 10.0, 20.0]);
+
+        let c = mat!(
+            5.0, 6.0, 0.0;
+            8.0, 9.0, 1.0;
+            2.0, 3.0, 4.0
+        );
+        let d = mat!(5.0, 6.0; 8.0, 9.0);
+
+        assert_eq!(c.view(0, 0, 2, 2).unwrap(), d.as_view());
+        assert_ne!(c.view(0, 1, 2, 2).unwrap(), d.as_view());
+        assert_ne!(c.view(0, 0, 1, 2).unwrap(), d.view(0, 0, 2, 1).unwrap());
+    }
+}
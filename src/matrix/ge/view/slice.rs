@@ -0,0 +1,173 @@
+use super::{MatrixView, MatrixViewMut};
+use crate::matrix::ge::Matrix;
+use crate::matrix::MatrixError;
+use crate::number::Number;
+use std::ops::{Bound, Range, RangeBounds};
+
+/// Resolves `range` for the dimension `len`.
+pub(crate) fn resolve_range<R>(range: R, len: usize) -> Result<Range<usize>, MatrixError>
+where
+    R: RangeBounds<usize>,
+{
+    let start = match range.start_bound() {
+        Bound::Included(&s) => s,
+        Bound::Excluded(&s) => s.checked_add(1).ok_or(MatrixError::DimensionMismatch)?,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&e) => e.checked_add(1).ok_or(MatrixError::DimensionMismatch)?,
+        Bound::Excluded(&e) => e,
+        Bound::Unbounded => len,
+    };
+
+    if end < start || len < end {
+        return Err(MatrixError::DimensionMismatch);
+    }
+
+    Ok(start..end)
+}
+
+impl<T> Matrix<T>
+where
+    T: Number,
+{
+    /// # Slice
+    /// View of the rows and columns in the ranges. Call `to_matrix()` for an owned copy.
+    /// ```
+    /// use opensrdk_linear_algebra::*;
+    ///
+    /// let a = mat!(
+    ///   1.0, 2.0, 3.0;
+    ///   4.0, 5.0, 6.0
+    /// );
+    ///
+    /// assert_eq!(a.slice(1.., ..2).unwrap().to_matrix(), mat!(4.0, 5.0));
+    /// assert_eq!(a.slice(.., 2).unwrap().to_matrix(), mat!(3.0; 6.0));
+    /// ```
+    pub fn slice<R, C>(&self, rows: R, cols: C) -> Result<MatrixView<'_, T>, MatrixError>
+    where
+        R: SliceIndex,
+        C: SliceIndex,
+    {
+        self.as_view().into_slice(rows, cols)
+    }
+
+    pub fn slice_mut<R, C>(&mut self, rows: R, cols: C) -> Result<MatrixViewMut<'_, T>, MatrixError>
+    where
+        R: SliceIndex,
+        C: SliceIndex,
+    {
+        self.as_view_mut().into_slice_mut(rows, cols)
+    }
+
+    /// View of the row `i`. Its leading dimension is `rows`.
+    pub fn row(&self, i: usize) -> MatrixView<'_, T> {
+        self.slice(i, ..).expect("Dimension mismatch.")
+    }
+
+    pub fn row_mut(&mut self, i: usize) -> MatrixViewMut<'_, T> {
+        self.slice_mut(i, ..).expect("Dimension mismatch.")
+    }
+}
+
+impl<'a, T> MatrixView<'a, T>
+where
+    T: Number,
+{
+    pub fn into_slice<R, C>(self, rows: R, cols: C) -> Result<MatrixView<'a, T>, MatrixError>
+    where
+        R: SliceIndex,
+        C: SliceIndex,
+    {
+        let rows = rows.resolve(self.rows)?;
+        let cols = cols.resolve(self.cols)?;
+
+        self.into_view(rows.start, cols.start, rows.len(), cols.len())
+    }
+}
+
+impl<'a, T> MatrixViewMut<'a, T>
+where
+    T: Number,
+{
+    pub fn into_slice_mut<R, C>(self, rows: R, cols: C) -> Result<MatrixViewMut<'a, T>, MatrixError>
+    where
+        R: SliceIndex,
+        C: SliceIndex,
+    {
+        let rows = rows.resolve(self.rows)?;
+        let cols = cols.resolve(self.cols)?;
+
+        self.into_view_mut(rows.start, cols.start, rows.len(), cols.len())
+    }
+}
+
+/// Index or range of rows or columns accepted by `Matrix::slice`.
+pub trait SliceIndex {
+    fn resolve(self, len: usize) -> Result<Range<usize>, MatrixError>;
+}
+
+impl SliceIndex for usize {
+    fn resolve(self, len: usize) -> Result<Range<usize>, MatrixError> {
+        resolve_range(self..=self, len)
+    }
+}
+
+macro_rules! impl_slice_index {
+    {$($r: ty),*} => {
+        $(
+            impl SliceIndex for $r {
+                fn resolve(self, len: usize) -> Result<Range<usize>, MatrixError> {
+                    resolve_range(self, len)
+                }
+            }
+        )*
+    };
+}
+
+impl_slice_index! {
+    std::ops::Range<usize>,
+    std::ops::RangeFrom<usize>,
+    std::ops::RangeTo<usize>,
+    std::ops::RangeFull,
+    std::ops::RangeInclusive<usize>,
+    std::ops::RangeToInclusive<usize>
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    #[test]
+    fn it_works() {
+        let mut a = mat!(
+            1.0, 2.0, 3.0;
+            4.0, 5.0, 6.0;
+            7.0, 8.0, 9.0
+        );
+
+        assert_eq!(
+            a.slice(1..=2, 1..).unwrap().to_matrix(),
+            mat!(
+                5.0, 6.0;
+                8.0, 9.0
+            )
+        );
+        assert_eq!(a.row(1).to_matrix(), mat!(4.0, 5.0, 6.0));
+        assert!(a.slice(2..4, ..).is_err());
+        assert!(a.slice(.., 3).is_err());
+        assert!(a.slice(..=usize::MAX, ..).is_err());
+        assert!(a.slice(.., 1..=usize::MAX).is_err());
+
+        a.row_mut(2).fill(0.0);
+        a.slice_mut(..1, ..).unwrap()[(0, 2)] = -3.0;
+
+        assert_eq!(
+            a,
+            mat!(
+                1.0, 2.0, -3.0;
+                4.0, 5.0, 6.0;
+                0.0, 0.0, 0.0
+            )
+        );
+    }
+}
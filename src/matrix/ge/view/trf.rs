@@ -0,0 +1,83 @@
+use super::MatrixViewMut;
+use crate::matrix::MatrixError;
+use crate::number::c64;
+use lapack::{dgetrf, dpotrf, zgetrf, zpotrf};
+
+macro_rules! impl_view_trf {
+    {$t: ty, $potrf: ident, $getrf: ident} => {
+        impl<'a> MatrixViewMut<'a, $t> {
+            /// # Cholesky decomposition
+            /// in place. The lower triangle is overwritten with `L` and the upper triangle is not referenced.
+            pub fn potrf(&mut self) -> Result<(), MatrixError> {
+                let n = self.rows;
+                if n != self.cols {
+                    return Err(MatrixError::DimensionMismatch);
+                }
+
+                let mut info = 0;
+
+                unsafe {
+                    $potrf(b'L', n as i32, self.elems, self.ld as i32, &mut info);
+                }
+
+                match info {
+                    0 => Ok(()),
+                    _ => Err(MatrixError::LapackRoutineError {
+                        routine: stringify!($potrf).to_owned(),
+                        info,
+                    }),
+                }
+            }
+
+            /// # LU decomposition
+            /// in place. Returns the pivot indices as `Matrix::getrf` does.
+            pub fn getrf(&mut self) -> Result<Vec<i32>, MatrixError> {
+                let m = self.rows;
+                let n = self.cols;
+                let mut ipiv = vec![0; m.min(n)];
+                let mut info = 0;
+
+                unsafe {
+                    $getrf(
+                        m as i32,
+                        n as i32,
+                        self.elems,
+                        self.ld as i32,
+                        &mut ipiv,
+                        &mut info,
+                    );
+                }
+
+                match info {
+                    0 => Ok(ipiv),
+                    _ => Err(MatrixError::LapackRoutineError {
+                        routine: stringify!($getrf).to_owned(),
+                        info,
+                    }),
+                }
+            }
+        }
+    };
+}
+
+impl_view_trf! {f64, dpotrf, dgetrf}
+impl_view_trf! {c64, zpotrf, zgetrf}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    #[test]
+    fn it_works() {
+        let mut a = mat!(
+            9.0, 0.0, 0.0;
+            0.0, 4.0, 2.0;
+            0.0, 2.0, 5.0
+        );
+        a.view_mut(1, 1, 2, 2).unwrap().potrf().unwrap();
+
+        assert_eq!(a[(0, 0)], 9.0);
+        assert_eq!(a[(1, 1)], 2.0);
+        assert_eq!(a[(2, 1)], 1.0);
+        assert_eq!(a[(2, 2)], 2.0);
+    }
+}
@@ -0,0 +1,108 @@
+use super::{MatrixView, MatrixViewMut};
+use crate::matrix::MatrixError;
+use crate::number::c64;
+use lapack::{dgetrs, dpotrs, zgetrs, zpotrs};
+
+macro_rules! impl_view_trs {
+    {$t: ty, $potrs: ident, $getrs: ident} => {
+        impl<'a> MatrixView<'a, $t> {
+            /// # Solve equation
+            /// with `self` decomposed by `MatrixViewMut::potrf`. `b` is overwritten with the solution.
+            pub fn potrs(&self, b: &mut MatrixViewMut<$t>) -> Result<(), MatrixError> {
+                let n = self.rows;
+                if n != self.cols || n != b.rows {
+                    return Err(MatrixError::DimensionMismatch);
+                }
+
+                let mut info = 0;
+
+                unsafe {
+                    $potrs(
+                        b'L',
+                        n as i32,
+                        b.cols as i32,
+                        self.elems,
+                        self.ld as i32,
+                        b.elems,
+                        b.ld as i32,
+                        &mut info,
+                    );
+                }
+
+                match info {
+                    0 => Ok(()),
+                    _ => Err(MatrixError::LapackRoutineError {
+                        routine: stringify!($potrs).to_owned(),
+                        info,
+                    }),
+                }
+            }
+
+            /// # Solve equation
+            /// with `self` and `ipiv` given by `MatrixViewMut::getrf`. `b` is overwritten with the solution.
+            pub fn getrs(&self, ipiv: &[i32], b: &mut MatrixViewMut<$t>) -> Result<(), MatrixError> {
+                let n = self.rows;
+                if n != self.cols || n != b.rows || n != ipiv.len() {
+                    return Err(MatrixError::DimensionMismatch);
+                }
+
+                let mut info = 0;
+
+                unsafe {
+                    $getrs(
+                        b'N',
+                        n as i32,
+                        b.cols as i32,
+                        self.elems,
+                        self.ld as i32,
+                        ipiv,
+                        b.elems,
+                        b.ld as i32,
+                        &mut info,
+                    );
+                }
+
+                match info {
+                    0 => Ok(()),
+                    _ => Err(MatrixError::LapackRoutineError {
+                        routine: stringify!($getrs).to_owned(),
+                        info,
+                    }),
+                }
+            }
+        }
+    };
+}
+
+impl_view_trs! {f64, dpotrs, dgetrs}
+impl_view_trs! {c64, zpotrs, zgetrs}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    #[test]
+    fn it_works() {
+        // [A | b] stored side by side, solved without copying A or b.
+        let mut ab = mat!(
+            4.0, 2.0, 8.0;
+            2.0, 5.0, 9.0
+        );
+        let (mut a, mut b) = ab.as_view_mut().split_at_col(2);
+        a.potrf().unwrap();
+        a.as_view().potrs(&mut b).unwrap();
+
+        assert!((ab[(0, 2)] - 1.375).abs() < 1e-12);
+        assert!((ab[(1, 2)] - 1.25).abs() < 1e-12);
+
+        let mut ab = mat!(
+            0.0, 2.0, 4.0;
+            3.0, 1.0, 7.0
+        );
+        let (mut a, mut b) = ab.as_view_mut().split_at_col(2);
+        let ipiv = a.getrf().unwrap();
+        a.as_view().getrs(&ipiv, &mut b).unwrap();
+
+        assert!((ab[(0, 2)] - 5.0 / 3.0).abs() < 1e-12);
+        assert!((ab[(1, 2)] - 2.0).abs() < 1e-12);
+    }
+}
@@ -1,9 +1,16 @@
+pub(crate) mod ev;
+pub mod sum;
+pub(crate) mod trf;
+
+pub use ev::KroneckerSYEV;
+pub use trf::KroneckerPOTRF;
+
 use serde::Deserialize;
 use serde::Serialize;
 
 use crate::matrix::ge::*;
 use crate::matrix::MatrixError;
-use crate::number::Number;
+use crate::number::{c64, Number};
 
 #[derive(Clone, Debug, Default, PartialEq, Hash, Serialize, Deserialize)]
 pub struct KroneckerMatrices<T = f64>
@@ -54,52 +61,82 @@ where
         self.matrices
     }
 
+    /// Dense `a_1 ⊗ a_2 ⊗ ... ⊗ a_p`. The factors may have different shapes.
     pub fn prod(&self) -> Matrix<T> {
-        let bigp = self.matrices.len();
-        let rows = self.matrices[0].rows();
-        let cols = self.matrices[0].cols();
-        let elems_row = (0..rows.pow(bigp as u32))
-            .into_iter()
-            .map(|j| {
-                (0..cols.pow(bigp as u32))
-                    .into_iter()
-                    .map(|i| {
-                        let elem_a = (0..bigp - 1)
-                            .into_iter()
-                            .map(|p| {
-                                let k = bigp - 1 - p;
-                                let row =
-                                    ((j - (j % rows.pow(k as u32))) / rows.pow(k as u32)) % rows;
-                                let col =
-                                    ((i - (i % cols.pow(k as u32))) / cols.pow(k as u32)) % cols;
-                                self.matrices[p][(col, row)]
-                            })
-                            .product::<T>();
-                        let elem_b = self.matrices[bigp - 1][(i % cols, j % rows)];
-                        elem_a * elem_b
-                    })
-                    .collect::<Vec<T>>()
-            })
-            .collect::<Vec<Vec<T>>>()
-            .concat();
-        let result = Matrix::from(rows.pow(bigp as u32), elems_row).unwrap();
-        result
+        Matrix::from_fn(self.rows, self.cols, |i, j| {
+            let (mut i, mut j) = (i, j);
+            let mut elem = T::one();
+
+            for m in self.matrices.iter().rev() {
+                elem *= m[(i % m.rows(), j % m.cols())];
+                i /= m.rows();
+                j /= m.cols();
+            }
+
+            elem
+        })
     }
 }
 
-impl KroneckerMatrices {
-    pub fn vec_mul(&self, v: Vec<f64>) -> Result<Vec<f64>, MatrixError> {
-        let n = v.len();
+/// Applies `f_1 ⊗ ... ⊗ f_p` to `b`, where `f(p, X)` applies the `p`-th factor with `cols[p]` columns to every column of `X`.
+///
+/// Each factor is applied to the reshaped operand in turn, so the Kronecker product is never formed.
+pub(crate) fn kronecker_apply<T, F>(
+    cols: &[usize],
+    b: &Matrix<T>,
+    f: F,
+) -> Result<Matrix<T>, MatrixError>
+where
+    T: Number,
+    F: Fn(usize, Matrix<T>) -> Result<Matrix<T>, MatrixError>,
+{
+    if cols.iter().product::<usize>() != b.rows() {
+        return Err(MatrixError::DimensionMismatch);
+    }
 
-        if self.cols != n {
-            return Err(MatrixError::DimensionMismatch);
-        }
+    let m = b.cols();
+    let mut x = b.clone();
 
-        let u = self.prod().dot(&v.col_mat());
-        Ok(u.vec())
+    // The index of the factor just applied moves to the slowest position,
+    // so the column index of `b` ends up the fastest.
+    for p in (0..cols.len()).rev() {
+        x = f(p, x.reshape(cols[p]))?.t();
     }
+
+    Ok(x.reshape(m).t())
+}
+
+macro_rules! impl_kronecker_mul {
+    {$t: ty} => {
+        impl KroneckerMatrices<$t> {
+            /// `(a_1 ⊗ ... ⊗ a_p) * B` without forming the Kronecker product.
+            ///
+            /// Costs `O(N Σ n_i)` per column.
+            pub fn mat_mul(&self, b: &Matrix<$t>) -> Result<Matrix<$t>, MatrixError> {
+                if self.cols != b.rows() {
+                    return Err(MatrixError::DimensionMismatch);
+                }
+
+                let cols = self.matrices.iter().map(|a| a.cols()).collect::<Vec<_>>();
+
+                kronecker_apply(&cols, b, |p, x| Ok(self.matrices[p].dot(&x)))
+            }
+
+            /// `(a_1 ⊗ ... ⊗ a_p) * v` without forming the Kronecker product.
+            pub fn vec_mul(&self, v: Vec<$t>) -> Result<Vec<$t>, MatrixError> {
+                if self.cols != v.len() {
+                    return Err(MatrixError::DimensionMismatch);
+                }
+
+                Ok(self.mat_mul(&v.col_mat())?.vec())
+            }
+        }
+    };
 }
 
+impl_kronecker_mul! {f64}
+impl_kronecker_mul! {c64}
+
 #[cfg(test)]
 mod tests {
     use crate::*;
@@ -130,4 +167,44 @@ mod tests {
         assert_eq!(ab1[(0, 0)], c1[(0, 0)]);
         assert_eq!(ab1[(1, 0)], c1[(1, 0)]);
     }
+
+    #[test]
+    fn heterogeneous() {
+        let a = mat![
+            1.0, 2.0, 3.0;
+            4.0, 5.0, 6.0
+        ];
+        let b = mat![
+            1.0, -1.0;
+            2.0, 0.5;
+            0.0, 3.0
+        ];
+        let c = mat![
+            2.0;
+            -1.0
+        ];
+        let abc = KroneckerMatrices::new(vec![a.clone(), b.clone(), c.clone()]);
+        let dense = abc.prod();
+
+        assert_eq!(dense.rows(), 12);
+        assert_eq!(dense.cols(), 6);
+        assert_eq!(dense[(7, 3)], a[(1, 1)] * b[(0, 1)] * c[(1, 0)]);
+        assert_eq!(dense[(11, 5)], a[(1, 2)] * b[(2, 1)] * c[(1, 0)]);
+
+        let x = Matrix::from(6, (0..12).map(|k| k as f64 - 5.0).collect()).unwrap();
+        let y = abc.mat_mul(&x).unwrap();
+        let expected = dense.dot(&x);
+
+        assert_eq!(y, expected);
+
+        let z = KroneckerMatrices::new(vec![
+            Matrix::<c64>::from(2, vec![c64::new(1.0, 1.0), c64::new(0.0, 2.0)]).unwrap(),
+            Matrix::<c64>::from(1, vec![c64::new(3.0, 0.0), c64::new(0.0, -1.0)]).unwrap(),
+        ]);
+        let v = vec![c64::new(1.0, 0.0), c64::new(2.0, 1.0)];
+        let zv = z.vec_mul(v.clone()).unwrap();
+        let expected = z.prod().dot(&v.col_mat()).vec();
+
+        assert_eq!(zv, expected);
+    }
 }
@@ -0,0 +1,170 @@
+use super::{kronecker_apply, KroneckerMatrices};
+use crate::matrix::ge::Matrix;
+use crate::matrix::MatrixError;
+use crate::number::Number;
+use serde::{Deserialize, Serialize};
+
+/// # Kronecker sum
+/// `a_1 ⊕ a_2 = a_1 ⊗ I + I ⊗ a_2` for square matrices, and `a_1 ⊕ ... ⊕ a_p` in the same manner.
+#[derive(Clone, Debug, Default, PartialEq, Hash, Serialize, Deserialize)]
+pub struct KroneckerSum<T = f64>
+where
+    T: Number,
+{
+    matrices: Vec<Matrix<T>>,
+    dim: usize,
+}
+
+impl<T> KroneckerSum<T>
+where
+    T: Number,
+{
+    /// The code below means that `a = a_1 ⊕ a_2`
+    /// ```
+    /// use opensrdk_linear_algebra::*;
+    ///
+    /// let a_1 = Matrix::<f64>::new(2, 2);
+    /// let a_2 = Matrix::<f64>::new(3, 3);
+    /// let a = KroneckerSum::new(vec![a_1, a_2]).unwrap();
+    /// ```
+    pub fn new(matrices: Vec<Matrix<T>>) -> Result<Self, MatrixError> {
+        if matrices.iter().any(|m| m.rows() != m.cols()) {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        let dim = matrices.iter().map(|m| m.rows()).product();
+
+        Ok(Self { matrices, dim })
+    }
+
+    pub fn matrices(&self) -> &[Matrix<T>] {
+        &self.matrices
+    }
+
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    pub fn eject(self) -> Vec<Matrix<T>> {
+        self.matrices
+    }
+
+    /// Dense `a_1 ⊕ ... ⊕ a_p`.
+    pub fn mat(&self) -> Matrix<T> {
+        let n = self.dim;
+
+        Matrix::from_fn(n, n, |i, j| {
+            let (mut i, mut j) = (i, j);
+            let mut elem = T::default();
+            let mut off_diagonals = 0;
+            let mut term = T::default();
+
+            // Only the terms where the other indices agree contribute.
+            for m in self.matrices.iter().rev() {
+                let (ip, jp) = (i % m.rows(), j % m.cols());
+                if ip != jp {
+                    off_diagonals += 1;
+                    term = m[(ip, jp)];
+                } else {
+                    elem += m[(ip, jp)];
+                }
+                i /= m.rows();
+                j /= m.cols();
+            }
+
+            match off_diagonals {
+                0 => elem,
+                1 => term,
+                _ => T::default(),
+            }
+        })
+    }
+}
+
+impl KroneckerSum {
+    /// `(a_1 ⊕ ... ⊕ a_p) * B` without forming the Kronecker sum.
+    pub fn mat_mul(&self, b: &Matrix) -> Result<Matrix, MatrixError> {
+        let cols = self.matrices.iter().map(|a| a.rows()).collect::<Vec<_>>();
+        let mut c = Matrix::new(b.rows(), b.cols());
+
+        for q in 0..cols.len() {
+            c += kronecker_apply(&cols, b, |p, x| {
+                Ok(if p == q { self.matrices[p].dot(&x) } else { x })
+            })?;
+        }
+
+        Ok(c)
+    }
+
+    /// `(a_1 ⊕ ... ⊕ a_p) * v` without forming the Kronecker sum.
+    pub fn vec_mul(&self, v: Vec<f64>) -> Result<Vec<f64>, MatrixError> {
+        if self.dim != v.len() {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        Ok(self.mat_mul(&Matrix::unvec(v, self.dim, 1)?)?.vec())
+    }
+
+    /// # Eigen decomposition
+    /// for Kronecker sum of symmetric matrices, from the factors.
+    ///
+    /// The eigenvalues are `lambda_1 ⊕ ... ⊕ lambda_p` and the eigenvectors are `q_1 ⊗ ... ⊗ q_p`.
+    ///
+    /// `(lambda, q)`
+    pub fn syev(self) -> Result<(Vec<f64>, KroneckerMatrices), MatrixError> {
+        let (lambdas, qs): (Vec<_>, Vec<_>) = self
+            .eject()
+            .into_iter()
+            .map(|a| a.syev())
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .unzip();
+
+        let lambda = lambdas.iter().fold(vec![0.0], |acc, lambda| {
+            acc.iter()
+                .flat_map(|&a| lambda.iter().map(move |&l| a + l))
+                .collect()
+        });
+
+        Ok((lambda, KroneckerMatrices::new(qs)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    #[test]
+    fn it_works() {
+        let a = mat![
+            2.0, -1.0;
+            -1.0, 2.0
+        ];
+        let b = mat![
+            1.0, 0.5, 0.0;
+            0.5, 3.0, 0.5;
+            0.0, 0.5, 2.0
+        ];
+        let ab = KroneckerSum::new(vec![a.clone(), b.clone()]).unwrap();
+        let dense = ab.mat();
+        let expected = KroneckerMatrices::new(vec![a, Matrix::<f64>::identity(3)]).prod()
+            + KroneckerMatrices::new(vec![Matrix::<f64>::identity(2), b]).prod();
+
+        assert_eq!(dense, expected);
+
+        let x = (0..6).map(|k| k as f64 - 2.5).collect::<Vec<_>>();
+        let y = ab.vec_mul(x.clone()).unwrap();
+
+        assert_eq!(y, dense.dot(&x.col_mat()).vec());
+
+        let (lambda, q) = ab.syev().unwrap();
+        let q = q.prod();
+        let qlqt = q
+            .clone()
+            .scale_cols(&DiagonalMatrix::new(lambda))
+            .unwrap()
+            .dot(&q.t());
+
+        for k in 0..36 {
+            assert!((qlqt.elems()[k] - dense.elems()[k]).abs() < 1e-12);
+        }
+    }
+}
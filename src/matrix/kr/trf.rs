@@ -0,0 +1,87 @@
+use super::{kronecker_apply, KroneckerMatrices};
+use crate::matrix::ge::sy_he::po::trf::POTRF;
+use crate::matrix::ge::Matrix;
+use crate::matrix::MatrixError;
+
+/// Cholesky decomposition of every factor, `a_1 ⊗ ... ⊗ a_p = (l_1 ⊗ ... ⊗ l_p) * (l_1 ⊗ ... ⊗ l_p)^T`.
+#[derive(Clone, Debug)]
+pub struct KroneckerPOTRF(pub Vec<POTRF>);
+
+impl KroneckerMatrices {
+    /// # Cholesky decomposition
+    /// for Kronecker product of positive definite matrices, factor by factor.
+    pub fn potrf(self) -> Result<KroneckerPOTRF, MatrixError> {
+        let potrfs = self
+            .eject()
+            .into_iter()
+            .map(|a| a.potrf())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(KroneckerPOTRF(potrfs))
+    }
+}
+
+impl KroneckerPOTRF {
+    /// Dimension of the Kronecker product.
+    pub fn dim(&self) -> usize {
+        self.0.iter().map(|POTRF(l)| l.rows()).product()
+    }
+
+    /// # Solve equation
+    /// `x = (a_1 ⊗ ... ⊗ a_p)^-1 * b` by solving with every factor in turn.
+    pub fn solve(&self, b: Matrix) -> Result<Matrix, MatrixError> {
+        let cols = self.0.iter().map(|POTRF(l)| l.rows()).collect::<Vec<_>>();
+
+        kronecker_apply(&cols, &b, |p, x| self.0[p].potrs(x))
+    }
+
+    /// # Log determinant
+    /// `ln det(a_1 ⊗ ... ⊗ a_p) = Σ_i (N / n_i) * ln det(a_i)`
+    pub fn logdet(&self) -> f64 {
+        let n = self.dim();
+
+        self.0
+            .iter()
+            .map(|POTRF(l)| {
+                let n_i = l.rows();
+                let logdet_i = 2.0 * (0..n_i).map(|j| l[(j, j)].ln()).sum::<f64>();
+
+                (n / n_i) as f64 * logdet_i
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::ge::sy_he::po::trf::POTRF;
+    use crate::*;
+    #[test]
+    fn it_works() {
+        let a = mat!(
+            4.0, 1.0;
+            1.0, 3.0
+        );
+        let b = mat!(
+            2.0, 0.5, 0.0;
+            0.5, 2.0, 0.5;
+            0.0, 0.5, 2.0
+        );
+        let ab = KroneckerMatrices::new(vec![a, b]);
+        let dense = ab.prod();
+        let x = Matrix::from(6, (0..12).map(|k| (k as f64).sin()).collect()).unwrap();
+        let b = dense.dot(&x);
+
+        let kpotrf = ab.potrf().unwrap();
+        let y = kpotrf.solve(b).unwrap();
+
+        for k in 0..12 {
+            assert!((x.elems()[k] - y.elems()[k]).abs() < 1e-12);
+        }
+
+        let POTRF(l) = dense.potrf().unwrap();
+        let logdet = 2.0 * (0..6).map(|i| l[(i, i)].ln()).sum::<f64>();
+
+        assert!((kpotrf.logdet() - logdet).abs() < 1e-12);
+    }
+}
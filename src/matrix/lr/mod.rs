@@ -0,0 +1,274 @@
+use crate::matrix::ge::random::standard_normal;
+use crate::matrix::ge::trf::GETRF;
+use crate::{DiagonalMatrix, Matrix, MatrixError, Vector};
+use rand::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// # Low rank plus diagonal matrix
+/// `A = D + U * C * V^T` where `D` is `n x n` diagonal, `U` and `V` are `n x k` and `C` is `k x k`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct LowRankPlusDiagonal {
+    d: DiagonalMatrix,
+    u: Matrix,
+    c: Matrix,
+    v: Matrix,
+}
+
+impl LowRankPlusDiagonal {
+    /// `A = D + U * C * V^T`
+    pub fn new(d: DiagonalMatrix, u: Matrix, c: Matrix, v: Matrix) -> Result<Self, MatrixError> {
+        let n = d.dim();
+        let k = c.rows();
+        if u.rows() != n || v.rows() != n || c.cols() != k || u.cols() != k || v.cols() != k {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        Ok(Self { d, u, c, v })
+    }
+
+    /// `A = D + U * C * U^T`
+    pub fn symmetric(d: DiagonalMatrix, u: Matrix, c: Matrix) -> Result<Self, MatrixError> {
+        let v = u.clone();
+
+        Self::new(d, u, c, v)
+    }
+
+    /// Dimension.
+    pub fn dim(&self) -> usize {
+        self.d.dim()
+    }
+
+    /// Rank of the low rank part.
+    pub fn rank(&self) -> usize {
+        self.c.rows()
+    }
+
+    pub fn d(&self) -> &DiagonalMatrix {
+        &self.d
+    }
+
+    pub fn u(&self) -> &Matrix {
+        &self.u
+    }
+
+    pub fn c(&self) -> &Matrix {
+        &self.c
+    }
+
+    pub fn v(&self) -> &Matrix {
+        &self.v
+    }
+
+    /// Returns `(d, u, c, v)`
+    pub fn eject(self) -> (DiagonalMatrix, Matrix, Matrix, Matrix) {
+        (self.d, self.u, self.c, self.v)
+    }
+
+    /// Dense `D + U * C * V^T`.
+    pub fn mat(&self) -> Matrix {
+        let mut a = self.u.dot(&self.c).dot(&self.v.t());
+        let n = self.dim();
+        for i in 0..n {
+            a[(i, i)] += self.d.d()[i];
+        }
+
+        a
+    }
+
+    /// `A * B` without forming `A`.
+    pub fn mat_mul(&self, b: &Matrix) -> Result<Matrix, MatrixError> {
+        if b.rows() != self.dim() {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let db = b.clone().scale_rows(&self.d)?;
+        let ucvb = self.u.dot(&self.c.dot(&self.v.t().dot(b)));
+
+        Ok(db + ucvb)
+    }
+
+    /// `A * v` without forming `A`.
+    pub fn vec_mul(&self, v: Vec<f64>) -> Result<Vec<f64>, MatrixError> {
+        Ok(self.mat_mul(&v.col_mat())?.vec())
+    }
+
+    /// `D^-1` and the LU decomposition of the capacitance matrix `I + V^T * D^-1 * U * C`.
+    fn capacitance(&self) -> Result<(DiagonalMatrix, GETRF), MatrixError> {
+        if self.d.d().contains(&0.0) {
+            return Err(MatrixError::Others(
+                "the Woodbury identity requires invertible `D`".into(),
+            ));
+        }
+
+        let d_inv = self.d.clone().powf(-1.0);
+        let mut k = self
+            .v
+            .t()
+            .dot(&self.u.clone().scale_rows(&d_inv)?.dot(&self.c));
+        for i in 0..self.rank() {
+            k[(i, i)] += 1.0;
+        }
+
+        Ok((d_inv, k.getrf()?))
+    }
+
+    /// # Solve equation
+    /// by the Woodbury identity
+    ///
+    /// `A^-1 = D^-1 - D^-1 * U * C * (I + V^T * D^-1 * U * C)^-1 * V^T * D^-1`
+    ///
+    /// Only a `k x k` matrix is factorized, so `C` may be singular, but `D` must be invertible.
+    pub fn solve(&self, b: Matrix) -> Result<Matrix, MatrixError> {
+        if b.rows() != self.dim() {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let (d_inv, k) = self.capacitance()?;
+        let d_inv_b = b.scale_rows(&d_inv)?;
+        let z = k.getrs(self.v.t().dot(&d_inv_b))?;
+        let correction = self.u.dot(&self.c.dot(&z)).scale_rows(&d_inv)?;
+
+        Ok(d_inv_b - correction)
+    }
+
+    /// # Log determinant
+    /// by the matrix determinant lemma
+    ///
+    /// `det(A) = det(D) * det(I + V^T * D^-1 * U * C)`
+    ///
+    /// Returns `ln|det(A)|`, which is `ln det(A)` for positive definite `A`.
+    /// `D` must be invertible.
+    pub fn logdet(&self) -> Result<f64, MatrixError> {
+        let (_, GETRF(lu, _)) = self.capacitance()?;
+        let ln_det_d = self.d.d().iter().map(|di| di.abs().ln()).sum::<f64>();
+        let ln_det_k = (0..self.rank()).map(|i| lu[(i, i)].abs().ln()).sum::<f64>();
+
+        Ok(ln_det_d + ln_det_k)
+    }
+
+    /// # Sample
+    /// from `N(0, A)`.
+    ///
+    /// `A` must be `D + U * C * U^T` with nonnegative `D` and positive definite `C`.
+    /// `x = D^(1/2) * z_1 + U * L * z_2` where `C = L * L^T`.
+    pub fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<Vec<f64>, MatrixError> {
+        if self.u != self.v {
+            return Err(MatrixError::Others("sampling requires `V = U`".into()));
+        }
+        if self.d.d().iter().any(|&di| di < 0.0) {
+            return Err(MatrixError::Others(
+                "sampling requires nonnegative `D`".into(),
+            ));
+        }
+
+        let n = self.dim();
+        let k = self.rank();
+        let l = self.c.clone().potrf()?.l();
+
+        let z1 = (0..n).map(|_| standard_normal(rng)).collect::<Vec<_>>();
+        let z2 = (0..k).map(|_| standard_normal(rng)).collect::<Vec<_>>();
+        let ulz = self.u.dot(&l.dot(&z2.col_mat()));
+
+        Ok((0..n)
+            .map(|i| self.d.d()[i].sqrt() * z1[i] + ulz[(i, 0)])
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::ge::sy_he::po::trf::POTRF;
+    use crate::*;
+    use rand::prelude::*;
+
+    #[test]
+    fn it_works() {
+        let d = DiagonalMatrix::new(vec![2.0, 3.0, 4.0, 5.0]);
+        let u = mat!(
+            1.0, 0.0;
+            0.5, 1.0;
+            0.0, 2.0;
+            1.0, 1.0
+        );
+        let c = mat!(
+            2.0, 0.5;
+            0.5, 1.0
+        );
+        let a = LowRankPlusDiagonal::symmetric(d, u, c).unwrap();
+        let dense = a.mat();
+
+        let x = vec![1.0, -1.0, 2.0, 0.5];
+        let ax = a.vec_mul(x.clone()).unwrap();
+        let expected = dense.dot(&x.clone().col_mat()).vec();
+        for i in 0..4 {
+            assert!((ax[i] - expected[i]).abs() < 1e-12);
+        }
+
+        let y = a.solve(ax.col_mat()).unwrap();
+        for i in 0..4 {
+            assert!((y[(i, 0)] - x[i]).abs() < 1e-12);
+        }
+
+        let POTRF(l) = dense.clone().potrf().unwrap();
+        let logdet = 2.0 * (0..4).map(|i| l[(i, i)].ln()).sum::<f64>();
+        assert!((a.logdet().unwrap() - logdet).abs() < 1e-12);
+
+        let mut rng = StdRng::seed_from_u64(1);
+        let samples = (0..20000)
+            .map(|_| a.sample(&mut rng).unwrap())
+            .collect::<Vec<_>>();
+        let cov = Matrix::from_fn(4, 4, |i, j| {
+            samples.iter().map(|s| s[i] * s[j]).sum::<f64>() / samples.len() as f64
+        });
+        for k in 0..16 {
+            assert!((cov.elems()[k] - dense.elems()[k]).abs() < 0.2);
+        }
+    }
+
+    #[test]
+    fn singular_d() {
+        let d = DiagonalMatrix::new(vec![1.0, 0.0]);
+        let u = mat!(
+            1.0;
+            1.0
+        );
+        let c = mat!(1.0);
+        let a = LowRankPlusDiagonal::symmetric(d, u, c).unwrap();
+
+        assert!(a.solve(mat!(1.0; 1.0)).is_err());
+        assert!(a.logdet().is_err());
+        assert!(a.sample(&mut StdRng::seed_from_u64(1)).is_ok());
+    }
+
+    #[test]
+    fn singular_c() {
+        let d = DiagonalMatrix::new(vec![1.0, 2.0, 3.0]);
+        let u = mat!(
+            1.0, 2.0;
+            0.0, 1.0;
+            1.0, 0.0
+        );
+        let c = mat!(
+            1.0, 1.0;
+            1.0, 1.0
+        );
+        let v = mat!(
+            1.0, 0.0;
+            2.0, 1.0;
+            0.0, 1.0
+        );
+        let a = LowRankPlusDiagonal::new(d, u, c, v).unwrap();
+        let b = mat!(
+            1.0;
+            2.0;
+            3.0
+        );
+        let x = a.solve(b.clone()).unwrap();
+        let ax = a.mat_mul(&x).unwrap();
+
+        for i in 0..3 {
+            assert!((ax[(i, 0)] - b[(i, 0)]).abs() < 1e-12);
+        }
+        assert!(a.sample(&mut StdRng::seed_from_u64(1)).is_err());
+    }
+}
@@ -6,6 +6,8 @@ pub mod di;
 pub mod ge;
 pub mod gt;
 pub mod kr;
+pub mod lr;
+pub mod pe;
 pub mod sp_hp;
 pub mod ss;
 pub mod st;
@@ -16,12 +18,16 @@ pub use ci::*;
 pub use di::*;
 pub use ge::{
     or_un::*,
+    stats::*,
     sy_he::{po::*, *},
     tr::*,
+    view::{MatrixView, MatrixViewMut, SliceIndex},
     *,
 };
 pub use gt::*;
-pub use kr::*;
+pub use kr::{sum::*, *};
+pub use lr::*;
+pub use pe::PermutationMatrix;
 pub use sp_hp::{pp::*, *};
 pub use ss::*;
 pub use st::{pt::*, *};
@@ -35,6 +41,10 @@ pub enum MatrixError {
     BlasRoutineError { routine: String, info: i32 },
     #[error("LAPACK routine error. routine: {routine}, info: {info}")]
     LapackRoutineError { routine: String, info: i32 },
+    #[error("Not positive definite. index: {index}")]
+    NotPositiveDefinite { index: usize },
+    #[error("Parse error. line: {line}, message: {message}")]
+    ParseError { line: usize, message: String },
     #[error("Others")]
     Others(Box<dyn Error + Send + Sync>),
 }
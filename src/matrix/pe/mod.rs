@@ -0,0 +1,160 @@
+use crate::{matrix::MatrixError, number::Number, Matrix};
+use serde::{Deserialize, Serialize};
+
+mod operators;
+mod permute;
+
+/// # Permutation matrix
+/// `P` with `P[(i, perm[i])] = 1`, so that `(P * x)[i] = x[perm[i]]`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PermutationMatrix {
+    perm: Vec<usize>,
+}
+
+impl PermutationMatrix {
+    pub fn identity(dim: usize) -> Self {
+        Self {
+            perm: (0..dim).collect(),
+        }
+    }
+
+    /// - `perm`: `perm[i]` is the index moved to `i`. Each of `0..perm.len()` must appear once.
+    pub fn new(perm: Vec<usize>) -> Result<Self, MatrixError> {
+        let mut seen = vec![false; perm.len()];
+
+        for &p in perm.iter() {
+            if p >= perm.len() || seen[p] {
+                return Err(MatrixError::DimensionMismatch);
+            }
+            seen[p] = true;
+        }
+
+        Ok(Self { perm })
+    }
+
+    /// From the pivot indices of `?getrf`, which interchange the row `i` and `ipiv[i] - 1` in order.
+    /// `P * A = L * U` holds for the result.
+    pub fn from_ipiv(dim: usize, ipiv: &[i32]) -> Result<Self, MatrixError> {
+        let mut p = Self::identity(dim);
+
+        for (i, &ip) in ipiv.iter().enumerate() {
+            let k = ip as usize;
+            if ip < 1 || dim < k || dim <= i {
+                return Err(MatrixError::DimensionMismatch);
+            }
+            p.swap(i, k - 1);
+        }
+
+        Ok(p)
+    }
+
+    /// From the pivot indices of `?sytrf`/`?hetrf`/`?sptrf`/`?hptrf` with `uplo = 'L'`.
+    /// Negative pivots denote 2x2 blocks. `P * A * P^T = L * D * L^T` holds for the result.
+    pub fn from_sytrf_ipiv(ipiv: &[i32]) -> Result<Self, MatrixError> {
+        let n = ipiv.len();
+        let mut p = Self::identity(n);
+
+        let mut k = 0;
+        while k < n {
+            let step = if ipiv[k] > 0 { 1 } else { 2 };
+            let kp = ipiv[k].unsigned_abs() as usize;
+            if kp < 1 || n < kp || n < k + step {
+                return Err(MatrixError::DimensionMismatch);
+            }
+
+            p.swap(k + step - 1, kp - 1);
+            k += step;
+        }
+
+        Ok(p)
+    }
+
+    /// Dimension.
+    pub fn dim(&self) -> usize {
+        self.perm.len()
+    }
+
+    pub fn perm(&self) -> &[usize] {
+        &self.perm
+    }
+
+    /// Returns `self.perm`
+    pub fn eject(self) -> Vec<usize> {
+        self.perm
+    }
+
+    /// Composes the interchange of the rows `i` and `k` from the left.
+    pub fn swap(&mut self, i: usize, k: usize) {
+        self.perm.swap(i, k);
+    }
+
+    /// # Inverse
+    /// which is also the transpose.
+    pub fn inv(&self) -> Self {
+        let mut perm = vec![0; self.dim()];
+        self.perm.iter().enumerate().for_each(|(i, &p)| perm[p] = i);
+
+        Self { perm }
+    }
+
+    /// # Composition
+    /// `self * rhs`
+    pub fn compose(&self, rhs: &PermutationMatrix) -> Result<Self, MatrixError> {
+        if self.dim() != rhs.dim() {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        Ok(Self {
+            perm: self.perm.iter().map(|&p| rhs.perm[p]).collect(),
+        })
+    }
+
+    pub fn mat<T>(&self) -> Matrix<T>
+    where
+        T: Number,
+    {
+        let n = self.dim();
+
+        Matrix::from_fn(n, n, |i, j| {
+            if self.perm[i] == j {
+                T::one()
+            } else {
+                T::default()
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    #[test]
+    fn it_works() {
+        let mut p = PermutationMatrix::identity(3);
+        p.swap(0, 2);
+
+        assert_eq!(p.perm(), &[2, 1, 0]);
+        assert_eq!(
+            p.mat::<f64>().dot(&mat!(1.0; 2.0; 3.0)),
+            mat!(3.0; 2.0; 1.0)
+        );
+        assert!(PermutationMatrix::new(vec![0, 0, 1]).is_err());
+    }
+
+    #[test]
+    fn compose() {
+        let p = PermutationMatrix::new(vec![1, 2, 0]).unwrap();
+        let q = PermutationMatrix::from_ipiv(3, &[3, 3, 3]).unwrap();
+
+        assert_eq!(q.perm(), &[2, 0, 1]);
+        assert_eq!(
+            p.compose(&q).unwrap().mat::<f64>(),
+            p.mat::<f64>().dot(&q.mat())
+        );
+        assert_eq!(p.compose(&p.inv()).unwrap(), PermutationMatrix::identity(3));
+        assert_eq!(p.inv().mat::<f64>(), p.mat::<f64>().t());
+
+        let r = PermutationMatrix::from_sytrf_ipiv(&[-3, -3, 3]).unwrap();
+        assert_eq!(r.perm(), &[0, 2, 1]);
+    }
+}
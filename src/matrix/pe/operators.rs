@@ -0,0 +1,87 @@
+use super::PermutationMatrix;
+use crate::number::Number;
+use crate::Matrix;
+use std::ops::Mul;
+
+impl Mul<PermutationMatrix> for PermutationMatrix {
+    type Output = PermutationMatrix;
+
+    fn mul(self, rhs: PermutationMatrix) -> Self::Output {
+        self.compose(&rhs).expect("Dimension mismatch.")
+    }
+}
+
+impl Mul<&PermutationMatrix> for &PermutationMatrix {
+    type Output = PermutationMatrix;
+
+    fn mul(self, rhs: &PermutationMatrix) -> Self::Output {
+        self.compose(rhs).expect("Dimension mismatch.")
+    }
+}
+
+impl<T> Mul<Matrix<T>> for &PermutationMatrix
+where
+    T: Number,
+{
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: Matrix<T>) -> Self::Output {
+        rhs.permute_rows(self).expect("Dimension mismatch.")
+    }
+}
+
+impl<T> Mul<&Matrix<T>> for &PermutationMatrix
+where
+    T: Number,
+{
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &Matrix<T>) -> Self::Output {
+        rhs.permute_rows(self).expect("Dimension mismatch.")
+    }
+}
+
+impl<T> Mul<&PermutationMatrix> for Matrix<T>
+where
+    T: Number,
+{
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: &PermutationMatrix) -> Self::Output {
+        self.permute_cols(&rhs.inv()).expect("Dimension mismatch.")
+    }
+}
+
+impl<T> Mul<Vec<T>> for &PermutationMatrix
+where
+    T: Number,
+{
+    type Output = Vec<T>;
+
+    fn mul(self, rhs: Vec<T>) -> Self::Output {
+        if self.dim() != rhs.len() {
+            panic!("Dimension mismatch.")
+        }
+
+        self.perm().iter().map(|&p| rhs[p]).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    #[test]
+    fn it_works() {
+        let p = PermutationMatrix::new(vec![1, 2, 0]).unwrap();
+        let a = mat!(
+            1.0, 2.0, 3.0;
+            4.0, 5.0, 6.0;
+            7.0, 8.0, 9.0
+        );
+
+        assert_eq!(&p * &a, p.mat::<f64>().dot(&a));
+        assert_eq!(a.clone() * &p, a.dot(&p.mat::<f64>()));
+        assert_eq!(&p * vec![1.0, 2.0, 3.0], vec![2.0, 3.0, 1.0]);
+        assert_eq!(&p * &p, p.compose(&p).unwrap());
+    }
+}
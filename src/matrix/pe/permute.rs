@@ -0,0 +1,93 @@
+use super::PermutationMatrix;
+use crate::matrix::MatrixError;
+use crate::number::Number;
+use crate::{Matrix, SparseMatrix};
+
+impl<T> Matrix<T>
+where
+    T: Number,
+{
+    /// `P * self`, moving the row `p.perm()[i]` to `i`.
+    pub fn permute_rows(&self, p: &PermutationMatrix) -> Result<Self, MatrixError> {
+        if self.rows() != p.dim() {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        Ok(self.select_rows(p.perm()))
+    }
+
+    /// `self * P^T`, moving the column `p.perm()[j]` to `j`.
+    pub fn permute_cols(&self, p: &PermutationMatrix) -> Result<Self, MatrixError> {
+        if self.cols() != p.dim() {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        Ok(self.select_cols(p.perm()))
+    }
+}
+
+impl<T> SparseMatrix<T>
+where
+    T: Number,
+{
+    /// `P * self`, moving the row `p.perm()[i]` to `i`.
+    pub fn permute_rows(&self, p: &PermutationMatrix) -> Result<Self, MatrixError> {
+        if self.rows != p.dim() {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let inv = p.inv();
+        let elems = self
+            .elems
+            .iter()
+            .map(|(&(i, j), &e)| ((inv.perm()[i], j), e))
+            .collect();
+
+        Ok(SparseMatrix::from(self.rows, self.cols, elems))
+    }
+
+    /// `self * P^T`, moving the column `p.perm()[j]` to `j`.
+    pub fn permute_cols(&self, p: &PermutationMatrix) -> Result<Self, MatrixError> {
+        if self.cols != p.dim() {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let inv = p.inv();
+        let elems = self
+            .elems
+            .iter()
+            .map(|(&(i, j), &e)| ((i, inv.perm()[j]), e))
+            .collect();
+
+        Ok(SparseMatrix::from(self.rows, self.cols, elems))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    #[test]
+    fn it_works() {
+        let p = PermutationMatrix::new(vec![2, 0, 1]).unwrap();
+        let a = mat!(
+            1.0, 2.0, 3.0;
+            4.0, 5.0, 6.0;
+            7.0, 8.0, 9.0
+        );
+
+        assert_eq!(a.permute_rows(&p).unwrap(), p.mat::<f64>().dot(&a));
+        assert_eq!(a.permute_cols(&p).unwrap(), a.dot(&p.mat::<f64>().t()));
+
+        let mut s = SparseMatrix::new(3, 3);
+        s[(0, 1)] = 2.0;
+        s[(2, 2)] = 9.0;
+
+        let sp = s.permute_rows(&p).unwrap();
+        assert_eq!(sp[(1, 1)], 2.0);
+        assert_eq!(sp[(0, 2)], 9.0);
+
+        let sp = s.permute_cols(&p).unwrap();
+        assert_eq!(sp[(0, 2)], 2.0);
+        assert_eq!(sp[(2, 0)], 9.0);
+    }
+}
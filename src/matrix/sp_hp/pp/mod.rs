@@ -1,3 +1,4 @@
 pub mod trf;
 pub mod tri;
 pub mod trs;
+pub(crate) mod up;
@@ -1,5 +1,6 @@
 use crate::matrix::MatrixError;
 use crate::number::c64;
+use crate::Matrix;
 use crate::Number;
 use crate::SymmetricPackedMatrix;
 use lapack::{dpptrf, zpptrf};
@@ -66,3 +67,32 @@ impl SymmetricPackedMatrix<c64> {
         }
     }
 }
+
+impl<T> PPTRF<T>
+where
+    T: Number,
+{
+    /// Lower triangular `L` as a dense matrix, where `A = L * L^*`.
+    pub fn l(&self) -> Matrix<T> {
+        self.0.to_mat()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    #[test]
+    fn it_works() {
+        let a = mat!(
+            4.0, 2.0;
+            2.0, 5.0
+        );
+        let l = SymmetricPackedMatrix::from_mat(&a)
+            .unwrap()
+            .pptrf()
+            .unwrap()
+            .l();
+
+        assert_eq!(l, mat!(2.0, 0.0; 1.0, 2.0));
+    }
+}
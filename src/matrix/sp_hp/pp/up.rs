@@ -0,0 +1,72 @@
+use super::trf::PPTRF;
+use crate::matrix::ge::sy_he::po::up::{rank1_update, CholeskyElement};
+use crate::matrix::MatrixError;
+
+impl<T> PPTRF<T>
+where
+    T: CholeskyElement,
+{
+    /// # Rank-1 update
+    /// Refactors `A + v * v^*` in place in O(n^2) without calling `pptrf` again.
+    pub fn update(&mut self, v: &[T]) -> Result<(), MatrixError> {
+        let n = self.0.dim();
+
+        rank1_update(
+            self.0.elems_mut(),
+            n,
+            |i, j| j * (2 * n - j + 1) / 2 + i - j,
+            v,
+            false,
+        )
+    }
+
+    /// # Rank-1 downdate
+    /// Refactors `A - v * v^*` in place in O(n^2).
+    /// Returns `MatrixError::NotPositiveDefinite` and leaves `self` unchanged if the result would not be positive definite.
+    pub fn downdate(&mut self, v: &[T]) -> Result<(), MatrixError> {
+        let n = self.0.dim();
+
+        rank1_update(
+            self.0.elems_mut(),
+            n,
+            |i, j| j * (2 * n - j + 1) / 2 + i - j,
+            v,
+            true,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    #[test]
+    fn it_works() {
+        let a = mat!(
+            4.0, 2.0;
+            2.0, 5.0
+        );
+        let mut l = SymmetricPackedMatrix::from_mat(&a)
+            .unwrap()
+            .pptrf()
+            .unwrap();
+        l.update(&[2.0, 1.0]).unwrap();
+
+        let expected = mat!(
+            8.0, 4.0;
+            4.0, 6.0
+        )
+        .potrf()
+        .unwrap()
+        .l();
+        let actual = l.l();
+
+        for i in 0..2 {
+            for j in 0..2 {
+                assert!((actual[(i, j)] - expected[(i, j)]).abs() < 1e-12);
+            }
+        }
+
+        l.downdate(&[2.0, 1.0]).unwrap();
+        assert!((l.l()[(1, 0)] - 1.0).abs() < 1e-12);
+    }
+}
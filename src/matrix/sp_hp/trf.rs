@@ -1,7 +1,9 @@
+use crate::matrix::ge::sy_he::trf::ldl_from_bunch_kaufman;
 use crate::matrix::MatrixError;
 use crate::number::c64;
 use crate::Number;
 use crate::SymmetricPackedMatrix;
+use crate::{Matrix, PermutationMatrix};
 use lapack::{dsptrf, zhptrf, zsptrf};
 use serde::Deserialize;
 use serde::Serialize;
@@ -83,3 +85,47 @@ impl SymmetricPackedMatrix<c64> {
         }
     }
 }
+
+impl<T> SPTRF<T>
+where
+    T: Number,
+{
+    /// Returns `(L, D, P)` where `P * A * P^T = L * D * L^T`. See `SYTRF::ldl`.
+    pub fn ldl(&self) -> (Matrix<T>, Matrix<T>, PermutationMatrix) {
+        ldl_from_bunch_kaufman(&self.0.to_mat(), &self.1, |e| e)
+    }
+}
+
+impl HPTRF {
+    /// Returns `(L, D, P)` where `P * A * P^T = L * D * L^*`. See `HETRF::ldl`.
+    pub fn ldl(&self) -> (Matrix<c64>, Matrix<c64>, PermutationMatrix) {
+        ldl_from_bunch_kaufman(&self.0.to_mat(), &self.1, |e| e.conj())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    #[test]
+    fn it_works() {
+        let a = mat!(
+            0.0, 1.0, 2.0;
+            1.0, 0.0, 3.0;
+            2.0, 3.0, 1.0
+        );
+        let (l, d, p) = SymmetricPackedMatrix::from_mat(&a)
+            .unwrap()
+            .sptrf()
+            .unwrap()
+            .ldl();
+        let p = p.mat::<f64>();
+        let pap = p.dot(&a).dot(&p.t());
+        let ldl = l.dot(&d).dot(&l.t());
+
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((pap[(i, j)] - ldl[(i, j)]).abs() < 1e-12);
+            }
+        }
+    }
+}
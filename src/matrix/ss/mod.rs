@@ -7,6 +7,7 @@ pub mod index;
 pub mod mul;
 pub mod mul_lhs;
 pub mod mul_rhs;
+pub(crate) mod random;
 
 #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct SparseMatrix<T = f64>
@@ -0,0 +1,45 @@
+use crate::matrix::ge::random::standard_normal;
+use crate::{MatrixError, SparseMatrix};
+use rand::prelude::*;
+
+impl SparseMatrix {
+    /// # Random sparse matrix
+    /// with `round(density * rows * cols)` nonzero elements drawn from the standard normal distribution at uniformly chosen positions.
+    pub fn random_normal<R: Rng + ?Sized>(
+        rows: usize,
+        cols: usize,
+        density: f64,
+        rng: &mut R,
+    ) -> Result<Self, MatrixError> {
+        let len = rows
+            .checked_mul(cols)
+            .ok_or(MatrixError::DimensionMismatch)?;
+        let nnz = (density.clamp(0.0, 1.0) * len as f64).round() as usize;
+        let positions = rand::seq::index::sample(rng, len, nnz);
+        let elems = positions
+            .into_iter()
+            .map(|k| ((k % rows, k / rows), standard_normal(rng)))
+            .collect();
+
+        Ok(SparseMatrix::from(rows, cols, elems))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use rand::prelude::*;
+
+    #[test]
+    fn it_works() {
+        let a = SparseMatrix::random_normal(10, 20, 0.1, &mut StdRng::seed_from_u64(1)).unwrap();
+        let b = SparseMatrix::random_normal(10, 20, 0.1, &mut StdRng::seed_from_u64(1)).unwrap();
+
+        assert_eq!(a.elems.len(), 20);
+        assert!(a.elems.keys().all(|&(i, j)| i < 10 && j < 20));
+        assert_eq!(a, b);
+        assert!(
+            SparseMatrix::random_normal(usize::MAX, 2, 0.0, &mut StdRng::seed_from_u64(1)).is_err()
+        );
+    }
+}
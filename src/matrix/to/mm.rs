@@ -0,0 +1,95 @@
+use crate::{matrix::MatrixError, number::c64, Matrix, ToeplitzMatrix};
+
+impl ToeplitzMatrix<c64> {
+    /// `T * B` in `O(n log n)` per column, by embedding `T` in a circulant matrix and using FFT.
+    pub fn mat_mul(&self, b: &Matrix<c64>) -> Result<Matrix<c64>, MatrixError> {
+        let n = self.dim();
+        if n != b.rows() {
+            return Err(MatrixError::DimensionMismatch);
+        }
+
+        let c = self.embedded_circulant();
+        let m = c.col_elems().len();
+        let padded = Matrix::from_fn(
+            m,
+            b.cols(),
+            |i, j| {
+                if i < n {
+                    b[(i, j)]
+                } else {
+                    c64::default()
+                }
+            },
+        );
+        let cb = c.mat_mul(&padded)?;
+
+        Ok(Matrix::from_fn(n, b.cols(), |i, j| cb[(i, j)]))
+    }
+
+    /// `T * v` in `O(n log n)`.
+    pub fn vec_mul(&self, v: Vec<c64>) -> Result<Vec<c64>, MatrixError> {
+        let n = v.len();
+
+        Ok(self.mat_mul(&Matrix::unvec(v, n, 1)?)?.vec())
+    }
+}
+
+impl ToeplitzMatrix {
+    /// `T * B` in `O(n log n)` per column, by embedding `T` in a circulant matrix and using FFT.
+    pub fn mat_mul(&self, b: &Matrix) -> Result<Matrix, MatrixError> {
+        let to = ToeplitzMatrix::<c64>::from(
+            self.col_elems().iter().map(|&e| c64::new(e, 0.0)).collect(),
+            self.row_elems().iter().map(|&e| c64::new(e, 0.0)).collect(),
+        )?;
+        let b = Matrix::from_fn(b.rows(), b.cols(), |i, j| c64::new(b[(i, j)], 0.0));
+        let tb = to.mat_mul(&b)?;
+
+        Ok(tb.real().0)
+    }
+
+    /// `T * v` in `O(n log n)`.
+    pub fn vec_mul(&self, v: Vec<f64>) -> Result<Vec<f64>, MatrixError> {
+        let n = v.len();
+
+        Ok(self.mat_mul(&Matrix::unvec(v, n, 1)?)?.vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    #[test]
+    fn it_works() {
+        let a = ToeplitzMatrix::from(vec![1.0, 2.0, 3.0, 4.0], vec![-1.0, 0.5, 5.0]).unwrap();
+        let dense = a.mat();
+
+        assert_eq!(dense[(0, 3)], 5.0);
+        assert_eq!(dense[(3, 1)], 3.0);
+        assert_eq!(dense[(1, 2)], -1.0);
+
+        let b = Matrix::from(4, (0..8).map(|k| k as f64 - 3.0).collect()).unwrap();
+        let ab = a.mat_mul(&b).unwrap();
+        let expected = dense.dot(&b);
+
+        for k in 0..8 {
+            assert!((ab.elems()[k] - expected.elems()[k]).abs() < 1e-12);
+        }
+
+        let c = a.embedded_circulant();
+
+        assert_eq!(c.col_elems(), &[1.0, 2.0, 3.0, 4.0, 5.0, 0.5, -1.0]);
+
+        let a = ToeplitzMatrix::from(
+            vec![c64::new(1.0, 1.0), c64::new(0.0, 2.0)],
+            vec![c64::new(3.0, -1.0)],
+        )
+        .unwrap();
+        let v = vec![c64::new(1.0, 0.0), c64::new(-2.0, 1.0)];
+        let av = a.vec_mul(v.clone()).unwrap();
+        let expected = a.mat().dot(&v.col_mat()).vec();
+
+        for i in 0..2 {
+            assert!((av[i] - expected[i]).norm() < 1e-12);
+        }
+    }
+}
@@ -1,5 +1,11 @@
+mod mm;
+mod sv;
+mod tri;
+
+pub use tri::GohbergSemencul;
+
 use crate::matrix::ci::CirculantMatrix;
-use crate::{matrix::MatrixError, number::Number};
+use crate::{matrix::MatrixError, number::Number, Matrix};
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 
@@ -24,7 +30,7 @@ where
     }
 
     /// - `col_elems`: First column elements. The length must be `dimension`.
-    /// - `row_elems`: First roe elements without first element. The length must be `dimension - 1`.
+    /// - `row_elems`: First row elements without first element. The length must be `dimension - 1`.
     pub fn from(col_elems: Vec<T>, row_elems: Vec<T>) -> Result<Self, MatrixError> {
         let dim = col_elems.len();
 
@@ -58,13 +64,23 @@ where
         (self.row_elems, self.col_elems)
     }
 
+    /// Circulant matrix of the dimension `2n - 1` whose upper left `n x n` block is `self`.
+    ///
+    /// The first column is `[t_0, ..., t_{n-1}, r_{n-1}, ..., r_1]`, where `t` is the first column and `r` is the first row.
     pub fn embedded_circulant(&self) -> CirculantMatrix<T> {
-        let col_elems = (0..self.dim())
-            .into_par_iter()
-            .chain((1..self.dim() - 1).into_par_iter().rev())
-            .map(|i| self.col_elems[i])
+        let col_elems = self
+            .col_elems
+            .par_iter()
+            .chain(self.row_elems.par_iter().rev())
+            .map(|&e| e)
             .collect();
 
         CirculantMatrix::<T>::new(col_elems)
     }
+
+    /// Dense matrix.
+    pub fn mat(&self) -> Matrix<T> {
+        // The lengths are checked on construction.
+        Matrix::toeplitz(&self.col_elems, &self.row_elems).expect("Dimension mismatch.")
+    }
 }
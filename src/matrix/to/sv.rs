@@ -0,0 +1,173 @@
+use crate::{matrix::MatrixError, Matrix, ToeplitzMatrix};
+
+/// Levinson recursion bordering the leading principal submatrices `T_k`.
+///
+/// Returns `(x, f, b, logdet)` where `T * x = y`, `T * f = e_1`, `T * b = e_n` and `logdet = ln|det(T)|`.
+fn levinson_recursion(
+    to: &ToeplitzMatrix,
+    y: &Matrix,
+    spd: bool,
+) -> Result<(Matrix, Vec<f64>, Vec<f64>, f64), MatrixError> {
+    let n = to.dim();
+    if n != y.rows() {
+        return Err(MatrixError::DimensionMismatch);
+    }
+    if n == 0 {
+        return Ok((y.clone(), vec![], vec![], 0.0));
+    }
+
+    let t = |k: isize| {
+        if k >= 0 {
+            to.col_elems()[k as usize]
+        } else {
+            to.row_elems()[(-k - 1) as usize]
+        }
+    };
+    // `ratio = det(T_{k+1}) / det(T_k)`
+    let check = |index: usize, ratio: f64| {
+        if spd && (ratio <= 0.0 || ratio.is_nan()) {
+            return Err(MatrixError::NotPositiveDefinite { index });
+        }
+        if ratio == 0.0 || !ratio.is_finite() {
+            return Err(MatrixError::Others(
+                format!(
+                    "leading principal submatrix of order {} is singular",
+                    index + 1
+                )
+                .into(),
+            ));
+        }
+
+        Ok(())
+    };
+
+    let m = y.cols();
+    let t0 = t(0);
+    check(0, t0)?;
+
+    let mut f = vec![1.0 / t0];
+    let mut b = vec![1.0 / t0];
+    let mut logdet = t0.abs().ln();
+    let mut x = Matrix::new(n, m);
+    for j in 0..m {
+        x[(0, j)] = y[(0, j)] / t0;
+    }
+
+    for k in 1..n {
+        let ef = (0..k).map(|i| t((k - i) as isize) * f[i]).sum::<f64>();
+        let eb = (0..k).map(|i| t(-(i as isize) - 1) * b[i]).sum::<f64>();
+        let denom = 1.0 - ef * eb;
+
+        let (f_new, b_new) = (0..=k)
+            .map(|i| {
+                let fi = if i < k { f[i] } else { 0.0 };
+                let bi = if i > 0 { b[i - 1] } else { 0.0 };
+
+                ((fi - ef * bi) / denom, (bi - eb * fi) / denom)
+            })
+            .unzip();
+        f = f_new;
+        b = b_new;
+
+        // The last element of `T_{k+1}^-1` is `det(T_k) / det(T_{k+1})`.
+        let ratio = 1.0 / b[k];
+        check(k, ratio)?;
+        logdet += ratio.abs().ln();
+
+        for j in 0..m {
+            let ex = (0..k).map(|i| t((k - i) as isize) * x[(i, j)]).sum::<f64>();
+            let c = y[(k, j)] - ex;
+            for i in 0..=k {
+                x[(i, j)] += c * b[i];
+            }
+        }
+    }
+
+    Ok((x, f, b, logdet))
+}
+
+impl ToeplitzMatrix {
+    /// # Solve equation
+    /// by the Levinson recursion in `O(n^2)` per column.
+    ///
+    /// Every leading principal submatrix must be nonsingular.
+    ///
+    /// `(x, logdet)` where `T * x = b` and `logdet = ln|det(T)|`.
+    pub fn levinson(&self, b: Matrix) -> Result<(Matrix, f64), MatrixError> {
+        let (x, _, _, logdet) = levinson_recursion(self, &b, false)?;
+
+        Ok((x, logdet))
+    }
+
+    /// # Solve equation
+    /// for symmetric positive definite Toeplitz matrix by the Levinson-Durbin recursion in `O(n^2)` per column.
+    ///
+    /// `(x, logdet)` where `T * x = b` and `logdet = ln det(T)`.
+    pub fn durbin(&self, b: Matrix) -> Result<(Matrix, f64), MatrixError> {
+        if self.row_elems() != &self.col_elems()[1.min(self.dim())..] {
+            return Err(MatrixError::Others(
+                "Toeplitz matrix is not symmetric".into(),
+            ));
+        }
+
+        let (x, _, _, logdet) = levinson_recursion(self, &b, true)?;
+
+        Ok((x, logdet))
+    }
+
+    /// `(T^-1 * e_1, T^-1 * e_n, ln|det(T)|)` for `GohbergSemencul`.
+    pub(crate) fn levinson_vectors(&self) -> Result<(Vec<f64>, Vec<f64>, f64), MatrixError> {
+        let (_, f, b, logdet) = levinson_recursion(self, &Matrix::new(self.dim(), 0), false)?;
+
+        Ok((f, b, logdet))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix::ge::sy_he::po::trf::POTRF;
+    use crate::matrix::ge::trf::GETRF;
+    use crate::*;
+    #[test]
+    fn it_works() {
+        let a = ToeplitzMatrix::from(vec![4.0, 1.0, -0.5, 0.25], vec![2.0, 0.5, -1.0]).unwrap();
+        let b = Matrix::from(4, (0..8).map(|k| (k as f64).sin()).collect()).unwrap();
+        let (x, logdet) = a.levinson(b.clone()).unwrap();
+        let ax = a.mat().dot(&x);
+
+        for k in 0..8 {
+            assert!((ax.elems()[k] - b.elems()[k]).abs() < 1e-12);
+        }
+
+        let GETRF(lu, _) = a.mat().getrf().unwrap();
+        let expected = (0..4).map(|i| lu[(i, i)].abs().ln()).sum::<f64>();
+
+        assert!((logdet - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn spd() {
+        let t = (0..6)
+            .map(|k| (-(k as f64).powi(2) / 8.0).exp())
+            .collect::<Vec<_>>();
+        let a = ToeplitzMatrix::from(t.clone(), t[1..].to_vec()).unwrap();
+        let b = Matrix::from(6, (0..6).map(|k| k as f64).collect()).unwrap();
+        let (x, logdet) = a.durbin(b.clone()).unwrap();
+        let ax = a.mat().dot(&x);
+
+        for k in 0..6 {
+            assert!((ax.elems()[k] - b.elems()[k]).abs() < 1e-9);
+        }
+
+        let POTRF(l) = a.mat().potrf().unwrap();
+        let expected = 2.0 * (0..6).map(|i| l[(i, i)].ln()).sum::<f64>();
+
+        assert!((logdet - expected).abs() < 1e-9);
+
+        let a = ToeplitzMatrix::from(vec![1.0, 2.0], vec![2.0]).unwrap();
+        match a.durbin(Matrix::new(2, 1)) {
+            Err(MatrixError::NotPositiveDefinite { index }) => assert_eq!(index, 1),
+            _ => panic!(),
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use crate::{matrix::MatrixError, Matrix, ToeplitzMatrix};
+use serde::{Deserialize, Serialize};
+
+/// # Gohberg-Semencul formula
+/// Representation of the inverse of a Toeplitz matrix by its first column `x` and last column `y`.
+///
+/// `T^-1 = (L(x) * U(J * y) - L(Z * y) * U(Z * J * x)) / x_0`
+///
+/// where `L(a)` and `U(a)` are lower and upper triangular Toeplitz matrices with the first column and row `a`, `J` is the reversal and `Z` is the down shift.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct GohbergSemencul {
+    x: Vec<f64>,
+    y: Vec<f64>,
+    logdet: f64,
+}
+
+impl ToeplitzMatrix {
+    /// # Inverse
+    /// in the Gohberg-Semencul representation, from the Levinson recursion in `O(n^2)`.
+    pub fn trench(&self) -> Result<GohbergSemencul, MatrixError> {
+        let (x, y, logdet) = self.levinson_vectors()?;
+
+        Ok(GohbergSemencul { x, y, logdet })
+    }
+}
+
+impl GohbergSemencul {
+    /// Dimension.
+    pub fn dim(&self) -> usize {
+        self.x.len()
+    }
+
+    /// First column of the inverse.
+    pub fn x(&self) -> &[f64] {
+        &self.x
+    }
+
+    /// Last column of the inverse.
+    pub fn y(&self) -> &[f64] {
+        &self.y
+    }
+
+    /// `ln|det(T)|`
+    pub fn logdet(&self) -> f64 {
+        self.logdet
+    }
+
+    /// Dense `T^-1` by the Trench algorithm in `O(n^2)`.
+    pub fn mat(&self) -> Matrix {
+        let n = self.dim();
+        let (x, y) = (&self.x, &self.y);
+        let mut inv = Matrix::new(n, n);
+
+        for i in 0..n {
+            inv[(i, 0)] = x[i];
+            inv[(0, i)] = y[n - 1 - i];
+        }
+        for j in 0..n.max(1) - 1 {
+            for i in 0..n - 1 {
+                inv[(i + 1, j + 1)] =
+                    inv[(i, j)] + (x[i + 1] * y[n - 2 - j] - y[i] * x[n - 1 - j]) / x[0];
+            }
+        }
+
+        inv
+    }
+
+    /// # Solve equation
+    /// `T^-1 * b` by the Gohberg-Semencul formula with four triangular Toeplitz products by FFT, in `O(n log n)` per column.
+    pub fn solve(&self, b: Matrix) -> Result<Matrix, MatrixError> {
+        let n = self.dim();
+        if n != b.rows() {
+            return Err(MatrixError::DimensionMismatch);
+        }
+        if n == 0 {
+            return Ok(b);
+        }
+
+        let zeros = vec![0.0; n - 1];
+        let lower = |a: Vec<f64>| ToeplitzMatrix::from(a, zeros.clone());
+        let upper = |a: Vec<f64>| {
+            let col = [vec![a[0]], zeros.clone()].concat();
+            ToeplitzMatrix::from(col, a[1..].to_vec())
+        };
+
+        let jy = self.y.iter().rev().copied().collect::<Vec<_>>();
+        let zy = [vec![0.0], self.y[..n - 1].to_vec()].concat();
+        let zjx = [vec![0.0], self.x[1..].iter().rev().copied().collect()].concat();
+
+        let first = lower(self.x.clone())?.mat_mul(&upper(jy)?.mat_mul(&b)?)?;
+        let second = lower(zy)?.mat_mul(&upper(zjx)?.mat_mul(&b)?)?;
+
+        Ok((first - second) * (1.0 / self.x[0]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    #[test]
+    fn it_works() {
+        let a = ToeplitzMatrix::from(vec![4.0, 1.0, -0.5, 0.25, 0.1], vec![2.0, 0.5, -1.0, 0.3])
+            .unwrap();
+        let inv = a.trench().unwrap();
+        let i = inv.mat().dot(&a.mat());
+
+        for r in 0..5 {
+            for c in 0..5 {
+                let expected = if r == c { 1.0 } else { 0.0 };
+                assert!((i[(r, c)] - expected).abs() < 1e-12);
+            }
+        }
+
+        let b = Matrix::from(5, (0..10).map(|k| (k as f64).cos()).collect()).unwrap();
+        let x = inv.solve(b.clone()).unwrap();
+        let (expected, logdet) = a.levinson(b).unwrap();
+
+        for k in 0..10 {
+            assert!((x.elems()[k] - expected.elems()[k]).abs() < 1e-12);
+        }
+        assert_eq!(inv.logdet(), logdet);
+    }
+}